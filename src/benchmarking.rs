@@ -4,10 +4,16 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Letters;
-use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller, Vec};
+use frame_benchmarking::{
+	account, benchmarks, impl_benchmark_test_suite, whitelisted_caller, BenchmarkError, Vec,
+};
 use frame_support::{
-	sp_runtime::traits::Bounded,
-	traits::{Currency, Get},
+	sp_runtime::{
+		traits::{Bounded, Hash, Zero},
+		Perbill,
+	},
+	traits::{Currency, EnsureOrigin, Get},
+	BoundedVec,
 };
 use frame_system::RawOrigin;
 
@@ -19,7 +25,80 @@ fn create_vec(n: u32) -> Vec<u8> {
 	v
 }
 
+// Bytes that hardly compress, so the compressed page is about as long as the page itself.
+fn create_random_vec(n: u32) -> Vec<u8> {
+	let mut state = 0x2545_f491u32;
+	let mut v = Vec::new();
+	for _ in 0..n {
+		state ^= state << 13;
+		state ^= state >> 17;
+		state ^= state << 5;
+		v.push(state as u8);
+	}
+	v
+}
+
+// A page that differs from the page of any other index, so each page is stored on its own.
+fn create_page(index: u32, len: u32) -> Vec<u8> {
+	let mut page = index.to_le_bytes().to_vec();
+	page.resize(len.max(4) as usize, b'.');
+	page
+}
+
+fn on_chain<T: Config>(page: Vec<u8>) -> Page<BoundedVec<u8, T::MaxPageLength>> {
+	Page::OnChain(page.try_into().unwrap())
+}
+
+fn create_book<T: Config>(owner: &T::AccountId, chapters: u32) -> Result<u64, BenchmarkError> {
+	let origin: T::Origin = RawOrigin::Signed(owner.clone()).into();
+	Letters::<T>::create_book(origin.clone(), create_vec(1))?;
+	let book_id = Letters::<T>::books_count();
+	for _ in 0..chapters {
+		Letters::<T>::init_letter(
+			origin.clone(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(Letters::<T>::all_letters_count());
+		Letters::<T>::add_chapter(origin.clone(), book_id, letter_id, create_vec(1))?;
+	}
+	Ok(book_id)
+}
+
+// Fills a letter with `pages` full pages of its own and proposes it to `entries` collections,
+// so a burn has to go through every page and every collection entry.
+fn fill_letter<T: Config>(
+	owner: &T::AccountId,
+	letter_id: T::Hash,
+	pages: u32,
+	entries: u32,
+) -> Result<(), BenchmarkError> {
+	let mut bounded: Vec<BoundedVec<u8, T::MaxPageLength>> = Vec::new();
+	for i in 0..pages {
+		bounded.push(create_page(i, T::MaxPageLength::get()).try_into().unwrap());
+	}
+	Letters::<T>::write_pages(
+		RawOrigin::Signed(owner.clone()).into(),
+		letter_id,
+		bounded.try_into().unwrap(),
+	)?;
+
+	let curator: T::AccountId = account("Curator", 0, 1);
+	T::Currency::make_free_balance_be(&curator, BalanceOf::<T>::max_value() / 2u32.into());
+	let origin: T::Origin = RawOrigin::Signed(curator).into();
+	for _ in 0..entries {
+		Letters::<T>::create_collection(origin.clone(), create_vec(1), Vec::new())?;
+		let collection_id = Letters::<T>::collections_count();
+		Letters::<T>::add_to_collection(origin.clone(), collection_id, letter_id)?;
+	}
+	assert_eq!(Letters::<T>::letter_entry_count(letter_id), entries);
+	Ok(())
+}
+
 benchmarks! {
+	where_clause { where AssetIdOf<T>: From<u32> }
+
 	init_letter {
 		let t in 0 .. T::MaxTitleLength::get() as u32;
 		let a in 0 .. T::MaxAuthorLength::get() as u32;
@@ -29,7 +108,7 @@ benchmarks! {
 
 		let caller: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&caller, 10000u32.into());
-	}: _(RawOrigin::Signed(caller), title, author)
+	}: _(RawOrigin::Signed(caller), title, author, TransferPolicy::Transferable)
 	verify {
 		let title = create_vec(t);
 		let author = create_vec(a);
@@ -48,7 +127,12 @@ benchmarks! {
 		let caller: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&caller, 10000u32.into());
 
-		Letters::<T>::init_letter(RawOrigin::Signed(caller.clone()).into(), title, author)?;
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			title,
+			author,
+			TransferPolicy::Transferable,
+		)?;
 
 		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
 		let page = create_vec(p);
@@ -56,7 +140,142 @@ benchmarks! {
 	verify {
 		let page = create_vec(p);
 		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
-		assert_eq!(Letters::<T>::read_page(letter_id, 0).unwrap(), page);
+		assert_eq!(Letters::<T>::read_page(letter_id, 0).unwrap(), on_chain::<T>(page));
+	}
+
+	write_pages {
+		let n in 1 .. T::MaxPageNum::get() as u32;
+		let b in 0 .. T::MaxPageLength::get() as u32;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		// all `b` bytes go to the first page, the others are as short as distinct pages can be
+		let mut pages: Vec<BoundedVec<u8, T::MaxPageLength>> = Vec::new();
+		pages.push(create_vec(b).try_into().unwrap());
+		for i in 1..n {
+			pages.push(create_page(i, 0).try_into().unwrap());
+		}
+		let pages: BoundedVec<_, T::MaxPageNum> = pages.try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), letter_id, pages)
+	verify {
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		assert_eq!(Letters::<T>::read_page(letter_id, 0).unwrap(), on_chain::<T>(create_vec(b)));
+		assert!(Letters::<T>::read_page(letter_id, (n - 1) as usize).is_ok());
+	}
+
+	append_to_page {
+		let b in 1 .. T::MaxPageLength::get() as u32;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+
+		// the last page has room for a single byte, the rest spills over into a new page
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		Letters::<T>::write_page(
+			RawOrigin::Signed(caller.clone()).into(),
+			letter_id,
+			create_vec(T::MaxPageLength::get() - 1),
+		)?;
+	}: _(RawOrigin::Signed(caller), letter_id, create_vec(b))
+	verify {
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		let full_page = create_vec(T::MaxPageLength::get());
+		assert_eq!(Letters::<T>::read_page(letter_id, 0).unwrap(), on_chain::<T>(full_page));
+		if b > 1 {
+			let page = Letters::<T>::read_page(letter_id, 1).unwrap();
+			assert_eq!(page, on_chain::<T>(create_vec(b - 1)));
+		}
+	}
+
+	write_offchain_page {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		let content = ContentId { codec: 0x55, digest: [1; 32], len: u32::MAX };
+	}: _(RawOrigin::Signed(caller), letter_id, content)
+	verify {
+		assert_eq!(Letters::<T>::read_page(letter_id, 0).unwrap(), Page::OffChain(content));
+	}
+
+	write_indexed_page {
+		let p in 0 .. T::MaxPageLength::get() as u32;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+	}: _(RawOrigin::Signed(caller), letter_id, create_vec(p))
+	verify {
+		assert!(Letters::<T>::verify_page(letter_id, 0, &create_vec(p)).unwrap());
+	}
+
+	write_compressed_page {
+		let c in 0 .. T::MaxPageLength::get() as u32;
+
+		// leave some room for the framing of the incompressible bytes
+		let page = create_random_vec(c - c / 16);
+		let compressed = lz4_flex::block::compress(&page);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+	}: _(RawOrigin::Signed(caller), letter_id, compressed, Compression::Lz4)
+	verify {
+		assert_eq!(Letters::<T>::read_page(letter_id, 0), Ok(on_chain::<T>(page)));
+	}
+
+	decompress_page {
+		let d in 0 .. T::MaxPageLength::get() as u32;
+
+		// a run of the same byte compresses best, so the fewest input bytes decompress into the
+		// longest page
+		let page = sp_std::vec![0u8; d as usize];
+		let compressed = lz4_flex::block::compress(&page);
+		let mut decompressed = None;
+	}: {
+		decompressed = Some(Letters::<T>::decompress_page(Compression::Lz4, &compressed)?);
+	}
+	verify {
+		assert_eq!(decompressed.map(|page| page.into_inner()), Some(page));
 	}
 
 	set_price {
@@ -66,12 +285,17 @@ benchmarks! {
 		let caller: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&caller, 10000u32.into());
 
-		Letters::<T>::init_letter(RawOrigin::Signed(caller.clone()).into(), title, author)?;
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			title,
+			author,
+			TransferPolicy::Transferable,
+		)?;
 
 		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
 		let new_price: T::Balance = 10u32.into();
 
-	}: _(RawOrigin::Signed(caller), letter_id, new_price)
+	}: _(RawOrigin::Signed(caller), letter_id, new_price, AssetKind::Asset(1u32.into()))
 	verify {
 		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
 		let (letter, _) = Letters::<T>::letter(letter_id).unwrap();
@@ -87,16 +311,81 @@ benchmarks! {
 		T::Currency::make_free_balance_be(&alice, 10000u32.into());
 		T::Currency::make_free_balance_be(&bob, 10000u32.into());
 
-		Letters::<T>::init_letter(RawOrigin::Signed(alice.clone()).into(), title, author)?;
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(alice.clone()).into(),
+			title,
+			author,
+			TransferPolicy::Transferable,
+		)?;
 		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
 
 	}: _(RawOrigin::Signed(alice), bob.clone(), letter_id)
 	verify {
 		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
-		assert_eq!(Letters::<T>::letter_of_owner_by_index((bob, 1)), letter_id);
+		assert!(Letters::<T>::letters_of(&bob).contains(&letter_id));
 	}
 
 	buy_letter {
+		let s in 0 .. T::MaxContributors::get() as u32;
+		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
+		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
+		let alice: T::AccountId = account("Alice", 0, 0);
+		let bob: T::AccountId = account("Bob", 0, 1);
+
+		T::Currency::make_free_balance_be(&alice, 10000u32.into());
+		T::Currency::make_free_balance_be(&bob, 10_000_000u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(alice.clone()).into(),
+			title,
+			author,
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+
+		let mut split = Vec::new();
+		for i in 0 .. s {
+			let who: T::AccountId = account("CoAuthor", i, 0);
+			T::Currency::make_free_balance_be(&who, 10000u32.into());
+			split.push((who, Perbill::from_rational(1, s + 1)));
+		}
+		Letters::<T>::set_revenue_split(RawOrigin::Signed(alice.clone()).into(), letter_id, split)?;
+		Letters::<T>::set_price(
+			RawOrigin::Signed(alice.clone()).into(),
+			letter_id,
+			1_000_000u32.into(),
+			AssetKind::Native,
+		)?;
+
+	}: _(RawOrigin::Signed(bob.clone()), letter_id, 1_000_000u32.into())
+	verify {
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		assert!(Letters::<T>::letters_of(&bob).contains(&letter_id));
+	}
+
+	create_edition {
+		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
+		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			title,
+			author,
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		let print_price: T::Balance = 10u32.into();
+
+	}: _(RawOrigin::Signed(caller), letter_id, 100, print_price)
+	verify {
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		assert_eq!(Letters::<T>::edition(letter_id).unwrap().max_supply, 100);
+	}
+
+	mint_print {
 		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
 		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
 		let alice: T::AccountId = account("Alice", 0, 0);
@@ -105,14 +394,693 @@ benchmarks! {
 		T::Currency::make_free_balance_be(&alice, 10000u32.into());
 		T::Currency::make_free_balance_be(&bob, 10000u32.into());
 
-		Letters::<T>::init_letter(RawOrigin::Signed(alice.clone()).into(), title, author)?;
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(alice.clone()).into(),
+			title,
+			author,
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		let print_price: T::Balance = 500u32.into();
+		Letters::<T>::create_edition(RawOrigin::Signed(alice).into(), letter_id, 100, print_price)?;
+
+	}: _(RawOrigin::Signed(bob.clone()), letter_id)
+	verify {
+		let print_id: T::Hash = Letters::<T>::letter_by_index(2);
+		assert!(Letters::<T>::letters_of(&bob).contains(&print_id));
+	}
+
+	create_collection {
+		let t in 0 .. T::MaxTitleLength::get() as u32;
+		let d in 0 .. T::MaxDescriptionLength::get() as u32;
+
+		let title = create_vec(t);
+		let description = create_vec(d);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+	}: _(RawOrigin::Signed(caller), title, description)
+	verify {
+		let (collection, _) = Letters::<T>::collection(1).unwrap();
+		assert_eq!(collection.title, create_vec(t));
+		assert_eq!(collection.description, create_vec(d));
+	}
+
+	add_to_collection {
+		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
+		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
+		let alice: T::AccountId = account("Alice", 0, 0);
+		let bob: T::AccountId = account("Bob", 0, 1);
+
+		T::Currency::make_free_balance_be(&alice, 10000u32.into());
+		T::Currency::make_free_balance_be(&bob, 10000u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(alice.clone()).into(),
+			title.clone(),
+			author,
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		Letters::<T>::create_collection(RawOrigin::Signed(bob.clone()).into(), title, Vec::new())?;
+		Letters::<T>::add_to_collection(RawOrigin::Signed(bob).into(), 1, letter_id)?;
+
+	}: _(RawOrigin::Signed(alice), 1, letter_id)
+	verify {
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		assert_eq!(Letters::<T>::collection_entries(1)[0], letter_id);
+	}
+
+	remove_from_collection {
+		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
+		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::init_letter(
+			origin.clone(),
+			title.clone(),
+			author,
+			TransferPolicy::Transferable,
+		)?;
 		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
-		Letters::<T>::set_price(RawOrigin::Signed(alice.clone()).into(), letter_id, 500u32.into())?;
+		Letters::<T>::create_collection(origin.clone(), title, Vec::new())?;
+		Letters::<T>::add_to_collection(origin, 1, letter_id)?;
+
+	}: _(RawOrigin::Signed(caller), 1, letter_id)
+	verify {
+		assert!(Letters::<T>::collection_entries(1).is_empty());
+	}
+
+	move_in_collection {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 100000u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::create_collection(origin.clone(), create_vec(1), Vec::new())?;
+		let n = T::MaxCollectionSize::get() as u64;
+		for i in 0 .. n {
+			Letters::<T>::init_letter(
+				origin.clone(),
+				create_vec(1),
+				create_vec(1),
+				TransferPolicy::Transferable,
+			)?;
+			let letter_id: T::Hash = Letters::<T>::letter_by_index(i + 1);
+			Letters::<T>::add_to_collection(origin.clone(), 1, letter_id)?;
+		}
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(n);
+
+	}: _(RawOrigin::Signed(caller), 1, letter_id, 0)
+	verify {
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(n);
+		assert_eq!(Letters::<T>::collection_entries(1)[0], letter_id);
+	}
+
+	destroy_collection {
+		let e in 0 .. T::MaxCollectionSize::get() as u32;
+		let p in 0 .. T::MaxPendingEntries::get() as u32;
+		let caller: T::AccountId = whitelisted_caller();
+		let bob: T::AccountId = account("Bob", 0, 1);
+		T::Currency::make_free_balance_be(&caller, 100000u32.into());
+		T::Currency::make_free_balance_be(&bob, 100000u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::create_collection(origin.clone(), create_vec(1), Vec::new())?;
+		// the letters of the caller are added right away, those of bob await the consent of bob
+		for i in 0 .. e + p {
+			let owner = if i < e { origin.clone() } else { RawOrigin::Signed(bob.clone()).into() };
+			Letters::<T>::init_letter(
+				owner,
+				create_vec(1),
+				create_vec(1),
+				TransferPolicy::Transferable,
+			)?;
+			let letter_id: T::Hash = Letters::<T>::letter_by_index(i as u64 + 1);
+			Letters::<T>::add_to_collection(origin.clone(), 1, letter_id)?;
+		}
+		assert_eq!(Letters::<T>::pending_entry_count(1), p);
+
+	}: _(RawOrigin::Signed(caller), 1)
+	verify {
+		assert!(Letters::<T>::collection(1).is_none());
+		assert_eq!(Letters::<T>::pending_entry_count(1), 0);
+	}
 
-	}: _(RawOrigin::Signed(bob.clone()), letter_id, 500u32.into())
+	create_book {
+		let t in 0 .. T::MaxTitleLength::get() as u32;
+		let title = create_vec(t);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+	}: _(RawOrigin::Signed(caller), title)
 	verify {
+		let (book, _) = Letters::<T>::book(1).unwrap();
+		assert_eq!(book.title, create_vec(t));
+	}
+
+	add_chapter {
+		let t in 0 .. T::MaxTitleLength::get() as u32;
+		let title = create_vec(t);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		let book_id = create_book::<T>(&caller, 0)?;
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::init_letter(
+			origin,
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
 		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
-		assert_eq!(Letters::<T>::letter_of_owner_by_index((bob, 1)), letter_id);
+	}: _(RawOrigin::Signed(caller), book_id, letter_id, title)
+	verify {
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		assert_eq!(Letters::<T>::chapter_of(letter_id), Some(book_id));
+	}
+
+	detach_chapter {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		let book_id = create_book::<T>(&caller, 1)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+	}: _(RawOrigin::Signed(caller), book_id, letter_id)
+	verify {
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		assert_eq!(Letters::<T>::chapter_of(letter_id), None);
+	}
+
+	set_book_price {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		let book_id = create_book::<T>(&caller, 0)?;
+		let new_price: T::Balance = 10u32.into();
+	}: _(RawOrigin::Signed(caller), book_id, new_price)
+	verify {
+		let (book, _) = Letters::<T>::book(book_id).unwrap();
+		assert_eq!(book.price, new_price);
+	}
+
+	transfer_book {
+		let c in 0 .. T::MaxChapters::get() as u32;
+		let alice: T::AccountId = account("Alice", 0, 0);
+		let bob: T::AccountId = account("Bob", 0, 1);
+
+		T::Currency::make_free_balance_be(&alice, 100000u32.into());
+		T::Currency::make_free_balance_be(&bob, 100000u32.into());
+
+		let book_id = create_book::<T>(&alice, c)?;
+	}: _(RawOrigin::Signed(alice), bob.clone(), book_id)
+	verify {
+		let (book, _) = Letters::<T>::book(book_id).unwrap();
+		assert_eq!(book.owner, bob);
+	}
+
+	buy_book {
+		let c in 0 .. T::MaxChapters::get() as u32;
+		let alice: T::AccountId = account("Alice", 0, 0);
+		let bob: T::AccountId = account("Bob", 0, 1);
+
+		T::Currency::make_free_balance_be(&alice, 100000u32.into());
+		T::Currency::make_free_balance_be(&bob, 100000u32.into());
+
+		let book_id = create_book::<T>(&alice, c)?;
+		Letters::<T>::set_book_price(RawOrigin::Signed(alice).into(), book_id, 500u32.into())?;
+	}: _(RawOrigin::Signed(bob.clone()), book_id, 500u32.into())
+	verify {
+		let (book, _) = Letters::<T>::book(book_id).unwrap();
+		assert_eq!(book.owner, bob);
+	}
+
+	destroy_book {
+		let c in 0 .. T::MaxChapters::get() as u32;
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 100000u32.into());
+
+		let book_id = create_book::<T>(&caller, c)?;
+	}: _(RawOrigin::Signed(caller), book_id)
+	verify {
+		assert!(Letters::<T>::book(book_id).is_none());
+	}
+
+	invite_contributor {
+		let caller: T::AccountId = whitelisted_caller();
+		let writer: T::AccountId = account("Writer", 0, 0);
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::init_letter(
+			origin.clone(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		for i in 1 .. T::MaxContributors::get() {
+			let who: T::AccountId = account("Writer", i, 0);
+			Letters::<T>::invite_contributor(origin.clone(), letter_id, who, Role::Writer)?;
+		}
+	}: _(RawOrigin::Signed(caller), letter_id, writer.clone(), Role::Writer)
+	verify {
+		assert_eq!(Letters::<T>::contributor_role(letter_id, &writer), Some(Role::Writer));
+	}
+
+	revoke_contributor {
+		let caller: T::AccountId = whitelisted_caller();
+		let writer: T::AccountId = account("Writer", 0, 0);
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::init_letter(
+			origin.clone(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		for i in 0 .. T::MaxContributors::get() {
+			let who: T::AccountId = account("Writer", i, 0);
+			Letters::<T>::invite_contributor(origin.clone(), letter_id, who, Role::Writer)?;
+		}
+	}: _(RawOrigin::Signed(caller), letter_id, writer.clone())
+	verify {
+		assert_eq!(Letters::<T>::contributor_role(letter_id, &writer), None);
+	}
+
+	seal_letter {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::init_letter(
+			origin,
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+	}: _(RawOrigin::Signed(caller), letter_id)
+	verify {
+		let (letter, _) = Letters::<T>::letter(letter_id).unwrap();
+		assert!(letter.sealed);
+	}
+
+	set_revenue_split {
+		let s in 0 .. T::MaxContributors::get() as u32;
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::init_letter(
+			origin,
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+
+		let mut split = Vec::new();
+		for i in 0 .. s {
+			let who: T::AccountId = account("CoAuthor", i, 0);
+			split.push((who, Perbill::from_rational(1, s + 1)));
+		}
+	}: _(RawOrigin::Signed(caller), letter_id, split)
+	verify {
+		assert_eq!(Letters::<T>::revenue_split(letter_id).len() as u32, s);
+	}
+
+	fractionalize {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::init_letter(
+			origin,
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		let shares: AssetBalanceOf<T> = 1000u32.into();
+	}: _(RawOrigin::Signed(caller), letter_id, shares)
+	verify {
+		assert_eq!(Letters::<T>::owner_of(letter_id), Some(Letters::<T>::account_id()));
+	}
+
+	redeem {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::init_letter(
+			origin.clone(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		let shares: AssetBalanceOf<T> = 1000u32.into();
+		Letters::<T>::fractionalize(origin, letter_id, shares)?;
+	}: _(RawOrigin::Signed(caller.clone()), letter_id)
+	verify {
+		assert_eq!(Letters::<T>::owner_of(letter_id), Some(caller));
+	}
+
+	burn_letter {
+		let p in 0 .. T::MaxPageNum::get() as u32;
+		let e in 0 .. T::MaxLetterEntries::get() as u32;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Soulbound,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		fill_letter::<T>(&caller, letter_id, p, e)?;
+	}: _(RawOrigin::Signed(caller.clone()), letter_id)
+	verify {
+		assert_eq!(Letters::<T>::owner_of(letter_id), None);
+		assert_eq!(Letters::<T>::owned_letter_count(&caller), 0);
+		assert_eq!(Letters::<T>::letter_entry_count(letter_id), 0);
+	}
+
+	freeze {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+	}: _(RawOrigin::Signed(caller), letter_id)
+	verify {
+		assert_eq!(Letters::<T>::frozen(letter_id), Some(Freezer::Owner));
+	}
+
+	thaw {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::init_letter(
+			origin.clone(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		Letters::<T>::freeze(origin, letter_id)?;
+	}: _(RawOrigin::Signed(caller), letter_id)
+	verify {
+		assert_eq!(Letters::<T>::frozen(letter_id), None);
+	}
+
+	force_redact_page {
+		let r in 1 .. T::MaxPageNum::get() as u32;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::init_letter(
+			origin.clone(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		// every page shares the same bytes, so all of them are redacted at once
+		let mut pages: Vec<BoundedVec<u8, T::MaxPageLength>> = Vec::new();
+		for _ in 0..r {
+			pages.push(create_vec(T::MaxPageLength::get()).try_into().unwrap());
+		}
+		Letters::<T>::write_pages(origin, letter_id, pages.try_into().unwrap())?;
+		let reason = T::Hashing::hash(b"abuse");
+		let moderator = T::ModerationOrigin::successful_origin();
+	}: _<T::Origin>(moderator, letter_id, 0, reason, DepositAction::Slash, r)
+	verify {
+		assert_eq!(Letters::<T>::redacted_page(letter_id, r - 1), Some(reason));
+	}
+
+	force_burn_letter {
+		let p in 0 .. T::MaxPageNum::get() as u32;
+		let e in 0 .. T::MaxLetterEntries::get() as u32;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let book_id = create_book::<T>(&caller, 1)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		fill_letter::<T>(&caller, letter_id, p, e)?;
+		let reason = T::Hashing::hash(b"abuse");
+		let moderator = T::ModerationOrigin::successful_origin();
+	}: _<T::Origin>(moderator, letter_id, reason, DepositAction::Slash)
+	verify {
+		assert_eq!(Letters::<T>::owner_of(letter_id), None);
+		assert_eq!(Letters::<T>::book(book_id).unwrap().0.chapters.len(), 0);
+	}
+
+	report_letter {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		let details = T::Hashing::hash(b"details");
+	}: _(RawOrigin::Signed(caller), letter_id, ReportReason::Abusive, details)
+	verify {
+		assert_eq!(Letters::<T>::reports(letter_id).len(), 1);
+	}
+
+	resolve_report {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		Letters::<T>::init_letter(
+			origin.clone(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		let details = T::Hashing::hash(b"details");
+		Letters::<T>::report_letter(origin, letter_id, ReportReason::Abusive, details)?;
+		let moderator = T::ModerationOrigin::successful_origin();
+	}: _<T::Origin>(moderator, letter_id, caller, DepositAction::Slash)
+	verify {
+		assert_eq!(Letters::<T>::reports(letter_id).len(), 0);
+	}
+
+	force_transfer {
+		let alice: T::AccountId = account("Alice", 0, 0);
+		let bob: T::AccountId = account("Bob", 0, 1);
+		T::Currency::make_free_balance_be(&alice, 10000u32.into());
+		T::Currency::make_free_balance_be(&bob, 10000u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(alice).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Soulbound,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		let force = T::ForceOrigin::successful_origin();
+	}: _<T::Origin>(force, letter_id, bob.clone())
+	verify {
+		assert_eq!(Letters::<T>::owner_of(letter_id), Some(bob));
+	}
+
+	force_set_price {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		let force = T::ForceOrigin::successful_origin();
+	}: _<T::Origin>(force, letter_id, 100u32.into(), AssetKind::Native)
+	verify {
+		assert_eq!(Letters::<T>::letter(letter_id).unwrap().0.price, 100u32.into());
+	}
+
+	force_set_deposit {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10000u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		let force = T::ForceOrigin::successful_origin();
+	}: _<T::Origin>(force, letter_id, 1000u32.into())
+	verify {
+		assert_eq!(Letters::<T>::letter(letter_id).unwrap().1, 1000u32.into());
+	}
+
+	force_rebuild_owner_index {
+		let n in 1 .. 100;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10_000_000u32.into());
+
+		let origin: T::Origin = RawOrigin::Signed(caller.clone()).into();
+		for _ in 0 .. n {
+			Letters::<T>::init_letter(
+				origin.clone(),
+				create_vec(1),
+				create_vec(1),
+				TransferPolicy::Transferable,
+			)?;
+		}
+		let force = T::ForceOrigin::successful_origin();
+	}: _<T::Origin>(force, caller.clone(), n)
+	verify {
+		assert_eq!(Letters::<T>::owned_letter_count(&caller), n as u64);
+	}
+
+	set_deposit_parameters {
+		let origin = T::ParameterOrigin::successful_origin();
+		let parameters = DepositParams {
+			letter_base: T::LetterDepositBase::get() * 2u32.into(),
+			letter_factor: T::LetterDepositFactor::get() * 2u32.into(),
+			page_base: T::PageDepositBase::get() * 2u32.into(),
+			page_factor: T::PageDepositFactor::get() * 2u32.into(),
+		};
+	}: _<T::Origin>(origin, parameters)
+	verify {
+		assert_eq!(Letters::<T>::deposit_parameters(), parameters);
+	}
+
+	recompute_deposit {
+		let p in 0 .. T::MaxPageNum::get() as u32;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		let mut pages: Vec<BoundedVec<u8, T::MaxPageLength>> = Vec::new();
+		for i in 0..p {
+			pages.push(create_page(i, 0).try_into().unwrap());
+		}
+		Letters::<T>::write_pages(
+			RawOrigin::Signed(caller.clone()).into(),
+			letter_id,
+			pages.try_into().unwrap(),
+		)?;
+
+		// every page deposit doubles
+		let mut parameters = Letters::<T>::deposit_parameters();
+		parameters.page_base = parameters.page_base * 2u32.into();
+		Letters::<T>::set_deposit_parameters(T::ParameterOrigin::successful_origin(), parameters)?;
+	}: _(RawOrigin::Signed(caller), letter_id)
+	verify {
+		let (letter, _) = Letters::<T>::letter(letter_id).unwrap();
+		for (index, page_hash) in letter.pages.iter().flatten().enumerate() {
+			let page = (letter_id, index as u32);
+			let (_, deposit) = Letters::<T>::page_deposit(page_hash, page).unwrap();
+			assert_eq!(deposit, deposit::page::<T>(4));
+		}
+	}
+
+	collect_rent {
+		let p in 1 .. T::MaxPageNum::get() as u32;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		// the worst case hashes every page of the letter to archive it
+		let mut pages: Vec<BoundedVec<u8, T::MaxPageLength>> = Vec::new();
+		for i in 0..p {
+			pages.push(create_page(i, T::MaxPageLength::get()).try_into().unwrap());
+		}
+		Letters::<T>::write_pages(
+			RawOrigin::Signed(caller.clone()).into(),
+			letter_id,
+			pages.try_into().unwrap(),
+		)?;
+
+		T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance());
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(
+			now + T::RentGracePeriod::get() + T::BlockNumber::from(2u32),
+		);
+	}: _(RawOrigin::Signed(caller), letter_id)
+	verify {
+		if !T::RentPerByte::get().is_zero() {
+			assert!(Letters::<T>::archived(letter_id).is_some());
+		}
+	}
+
+	restore_letter {
+		let n in 1 .. T::MaxPageNum::get() as u32;
+		let b in 0 .. T::MaxPageLength::get() as u32;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Letters::<T>::init_letter(
+			RawOrigin::Signed(caller.clone()).into(),
+			create_vec(1),
+			create_vec(1),
+			TransferPolicy::Transferable,
+		)?;
+		let letter_id: T::Hash = Letters::<T>::letter_by_index(1);
+		// all `b` bytes go to the first page, the others are as short as distinct pages can be
+		let mut pages: Vec<BoundedVec<u8, T::MaxPageLength>> = Vec::new();
+		pages.push(create_vec(b).try_into().unwrap());
+		for i in 1..n {
+			pages.push(create_page(i, 0).try_into().unwrap());
+		}
+		let pages: BoundedVec<_, T::MaxPageNum> = pages.try_into().unwrap();
+		Letters::<T>::write_pages(
+			RawOrigin::Signed(caller.clone()).into(),
+			letter_id,
+			pages.clone(),
+		)?;
+		Letters::<T>::archive_letter(letter_id)?;
+	}: _(RawOrigin::Signed(caller), letter_id, pages)
+	verify {
+		assert!(Letters::<T>::archived(letter_id).is_none());
+		assert_eq!(Letters::<T>::read_page(letter_id, 0).unwrap(), on_chain::<T>(create_vec(b)));
 	}
 }
 
@@ -0,0 +1,286 @@
+//! Deposit accounting for pallet-letters
+//!
+//! The reserve recorded next to a letter is held by the account in `DepositHolder`, while the
+//! deposit of a page stored off chain by a contributor is held by that contributor and recorded in
+//! `ContributorDeposits`. The deposit of a page with bytes on chain is held by whoever stored it
+//! and recorded in `PageDeposits`, so every page sharing the same bytes pays for itself and gets
+//! its deposit back on its own. Deposits only ever move by their difference: growing one
+//! reserves the increment, shrinking it releases the decrement, and a new holder pays for the
+//! whole reserve before the previous one gets it back.
+
+use super::*;
+use codec::MaxEncodedLen;
+use frame_support::{
+	dispatch::DispatchResult,
+	sp_runtime::traits::{Saturating, Zero},
+	traits::{OnUnbalanced, ReservableCurrency},
+};
+#[cfg(any(feature = "try-runtime", test))]
+use sp_std::collections::btree_map::BTreeMap;
+
+/// The change needed to bring a deposit to a new amount.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Delta<Balance> {
+	/// The deposit grows by this amount.
+	Reserve(Balance),
+	/// The deposit shrinks by this amount.
+	Release(Balance),
+}
+
+/// The change needed to bring a deposit from `old` to `new`.
+pub fn delta<Balance: PartialOrd + Saturating + Copy>(
+	old: Balance,
+	new: Balance,
+) -> Delta<Balance> {
+	if new >= old {
+		Delta::Reserve(new.saturating_sub(old))
+	} else {
+		Delta::Release(old.saturating_sub(new))
+	}
+}
+
+/// The deposit of a letter, charged for its title and author.
+pub fn letter<T: Config>(title_len: usize, author_len: usize) -> BalanceOf<T> {
+	let params = <DepositParameters<T>>::get();
+	params.letter_base +
+		params.letter_factor * (title_len as u32).into() +
+		params.letter_factor * (author_len as u32).into()
+}
+
+/// The deposit of a page of `page_len` bytes.
+pub fn page<T: Config>(page_len: usize) -> BalanceOf<T> {
+	let params = <DepositParameters<T>>::get();
+	params.page_base + params.page_factor * (page_len as u32).into()
+}
+
+/// The deposit of a page stored off chain, which only keeps its content identifier.
+pub fn offchain_page<T: Config>() -> BalanceOf<T> {
+	page::<T>(ContentId::max_encoded_len())
+}
+
+/// Reserves `amount` from `holder` as the first deposit of a new letter.
+pub fn open<T: Config>(
+	letter_id: T::Hash,
+	holder: &T::AccountId,
+	amount: BalanceOf<T>,
+) -> DispatchResult {
+	T::Currency::reserve(holder, amount)?;
+	<DepositHolder<T>>::insert(letter_id, holder);
+	Ok(())
+}
+
+/// Reserves `amount` more from the holder of `letter_id` and adds it to `reserve`.
+pub fn hold<T: Config>(
+	letter_id: T::Hash,
+	reserve: &mut BalanceOf<T>,
+	amount: BalanceOf<T>,
+) -> DispatchResult {
+	let holder = <DepositHolder<T>>::get(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+	T::Currency::reserve(&holder, amount)?;
+	*reserve = reserve.saturating_add(amount);
+	Ok(())
+}
+
+/// Reserves `amount` from a contributor for the pages they write on `letter_id`.
+pub fn hold_contributor<T: Config>(
+	letter_id: T::Hash,
+	who: &T::AccountId,
+	amount: BalanceOf<T>,
+) -> DispatchResult {
+	T::Currency::reserve(who, amount)?;
+	<ContributorDeposits<T>>::mutate(letter_id, who, |held| *held = held.saturating_add(amount));
+	Ok(())
+}
+
+/// Brings the reserve of `letter_id` from `reserve` to `target`, moving only the difference.
+pub fn set<T: Config>(
+	letter_id: T::Hash,
+	reserve: &mut BalanceOf<T>,
+	target: BalanceOf<T>,
+) -> DispatchResult {
+	let holder = <DepositHolder<T>>::get(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+	match delta(*reserve, target) {
+		Delta::Reserve(amount) => T::Currency::reserve(&holder, amount)?,
+		Delta::Release(amount) => {
+			T::Currency::unreserve(&holder, amount);
+		},
+	}
+	*reserve = target;
+	Ok(())
+}
+
+/// Brings the deposit a contributor holds for `letter_id` to `target`, moving only the difference.
+pub fn set_contributor<T: Config>(
+	letter_id: T::Hash,
+	who: &T::AccountId,
+	target: BalanceOf<T>,
+) -> DispatchResult {
+	match delta(<ContributorDeposits<T>>::get(letter_id, who), target) {
+		Delta::Reserve(amount) => T::Currency::reserve(who, amount)?,
+		Delta::Release(amount) => {
+			T::Currency::unreserve(who, amount);
+		},
+	}
+	<ContributorDeposits<T>>::insert(letter_id, who, target);
+	Ok(())
+}
+
+/// Reserves `amount` from `who` for the page at `index` of `letter_id`, which refers to the bytes
+/// stored under `page_hash`.
+pub fn hold_page<T: Config>(
+	page_hash: T::Hash,
+	letter_id: T::Hash,
+	index: u32,
+	who: &T::AccountId,
+	amount: BalanceOf<T>,
+) -> DispatchResult {
+	T::Currency::reserve(who, amount)?;
+	<PageDeposits<T>>::insert(page_hash, (letter_id, index), (who, amount));
+	Ok(())
+}
+
+/// Brings the deposit of the page at `index` of `letter_id` in line with the length of the bytes
+/// it refers to, moving only the difference.
+pub fn set_page<T: Config>(page_hash: T::Hash, letter_id: T::Hash, index: u32) -> DispatchResult {
+	let stored = <PageStore<T>>::get(page_hash).ok_or(Error::<T>::NonExistentPage)?;
+	let (who, held) =
+		<PageDeposits<T>>::get(page_hash, (letter_id, index)).ok_or(Error::<T>::NonExistentPage)?;
+	let target = page::<T>(stored.bytes.len());
+	match delta(held, target) {
+		Delta::Reserve(amount) => T::Currency::reserve(&who, amount)?,
+		Delta::Release(amount) => {
+			T::Currency::unreserve(&who, amount);
+		},
+	}
+	<PageDeposits<T>>::insert(page_hash, (letter_id, index), (who, target));
+	Ok(())
+}
+
+/// Moves the deposit of the page at `index` of `letter_id` from the bytes under `old_hash` to
+/// those under `new_hash`, bringing it to `target` held by `who`. Only the difference moves when
+/// `who` already holds it, otherwise `who` pays the whole deposit before the previous payer gets
+/// theirs back.
+pub fn move_page<T: Config>(
+	old_hash: T::Hash,
+	new_hash: T::Hash,
+	letter_id: T::Hash,
+	index: u32,
+	who: &T::AccountId,
+	target: BalanceOf<T>,
+) -> DispatchResult {
+	match <PageDeposits<T>>::get(old_hash, (letter_id, index)) {
+		Some((payer, held)) if payer == *who => match delta(held, target) {
+			Delta::Reserve(amount) => T::Currency::reserve(who, amount)?,
+			Delta::Release(amount) => {
+				T::Currency::unreserve(who, amount);
+			},
+		},
+		previous => {
+			T::Currency::reserve(who, target)?;
+			if let Some((payer, held)) = previous {
+				T::Currency::unreserve(&payer, held);
+			}
+		},
+	}
+	<PageDeposits<T>>::remove(old_hash, (letter_id, index));
+	<PageDeposits<T>>::insert(new_hash, (letter_id, index), (who, target));
+	Ok(())
+}
+
+/// Releases the deposit of the page at `index` of `letter_id`, returning or slashing it.
+/// Returns the amount released.
+pub fn release_page<T: Config>(
+	page_hash: T::Hash,
+	letter_id: T::Hash,
+	index: u32,
+	action: DepositAction,
+) -> BalanceOf<T> {
+	match <PageDeposits<T>>::take(page_hash, (letter_id, index)) {
+		Some((who, amount)) => {
+			release::<T>(&who, amount, action);
+			amount
+		},
+		None => Zero::zero(),
+	}
+}
+
+/// Releases `amount` of the reserve of `letter_id`, returning or slashing it.
+/// No more than the recorded reserve is ever released.
+pub fn release_from<T: Config>(
+	letter_id: T::Hash,
+	reserve: &mut BalanceOf<T>,
+	amount: BalanceOf<T>,
+	action: DepositAction,
+) {
+	let amount = amount.min(*reserve);
+	if let Some(holder) = <DepositHolder<T>>::get(letter_id) {
+		release::<T>(&holder, amount, action);
+	}
+	*reserve = reserve.saturating_sub(amount);
+}
+
+/// Hands the reserve of `letter_id` over to `to`, who holds it from then on.
+/// The previous holder gets it back only once `to` has paid for it.
+pub fn transfer<T: Config>(
+	letter_id: T::Hash,
+	to: &T::AccountId,
+	reserve: BalanceOf<T>,
+) -> DispatchResult {
+	let holder = <DepositHolder<T>>::get(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+	if holder == *to {
+		return Ok(())
+	}
+
+	T::Currency::reserve(to, reserve)?;
+	T::Currency::unreserve(&holder, reserve);
+	<DepositHolder<T>>::insert(letter_id, to);
+	Ok(())
+}
+
+/// Returns a reserved deposit to `who`, or slashes it to `Config::Slash`.
+pub fn release<T: Config>(who: &T::AccountId, amount: BalanceOf<T>, action: DepositAction) {
+	match action {
+		DepositAction::Refund => {
+			T::Currency::unreserve(who, amount);
+		},
+		DepositAction::Slash => {
+			let (imbalance, _) = T::Currency::slash_reserved(who, amount);
+			T::Slash::on_unbalanced(imbalance);
+		},
+	}
+}
+
+/// The deposits the pallet records for each account.
+#[cfg(any(feature = "try-runtime", test))]
+pub fn held<T: Config>() -> BTreeMap<T::AccountId, BalanceOf<T>> {
+	let mut held = BTreeMap::new();
+	let mut add = |who: T::AccountId, amount: BalanceOf<T>| {
+		let entry: &mut BalanceOf<T> = held.entry(who).or_default();
+		*entry = entry.saturating_add(amount);
+	};
+
+	for (letter_id, (_, reserve)) in <Letters<T>>::iter() {
+		if let Some(holder) = <DepositHolder<T>>::get(letter_id) {
+			add(holder, reserve);
+		}
+	}
+	for (_, who, deposit) in <ContributorDeposits<T>>::iter() {
+		add(who, deposit);
+	}
+	for (who, deposit) in <PageDeposits<T>>::iter_values() {
+		add(who, deposit);
+	}
+	for (_, (collection, reserve)) in <Collections<T>>::iter() {
+		add(collection.curator, reserve);
+	}
+	for (_, (book, reserve)) in <Books<T>>::iter() {
+		add(book.owner, reserve);
+	}
+	for (_, reports) in <Reports<T>>::iter() {
+		for report in reports {
+			add(report.reporter, report.bond);
+		}
+	}
+
+	held
+}
@@ -10,12 +10,23 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod deposit;
+pub mod migrations;
 pub mod weights;
-use frame_support::traits::Currency;
+use frame_support::traits::{tokens::fungibles, Currency};
 pub use weights::*;
 
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::AssetId;
+type AssetBalanceOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -23,11 +34,24 @@ pub mod pallet {
 	use frame_support::{
 		dispatch::DispatchResult,
 		pallet_prelude::*,
-		sp_runtime::traits::{Hash, Zero},
-		traits::{ExistenceRequirement, ReservableCurrency},
+		sp_runtime::{
+			traits::{
+				AccountIdConversion, Hash, One, SaturatedConversion, Saturating, TrailingZeroInput,
+				Zero,
+			},
+			Perbill,
+		},
+		traits::{
+			tokens::fungibles::{Create, Destroy, Inspect, Mutate, Transfer},
+			ExistenceRequirement, OnUnbalanced, ReservableCurrency, WithdrawReasons,
+		},
+		transactional, PalletId,
 	};
 	use frame_system::pallet_prelude::*;
 	use scale_info::{prelude::vec::Vec, TypeInfo};
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+	use sp_core::offchain::StorageKind;
 
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
@@ -37,7 +61,187 @@ pub mod pallet {
 		pub title: BoundedVec<u8, T::MaxTitleLength>,
 		pub author: BoundedVec<u8, T::MaxAuthorLength>,
 		pub price: T::Balance,
-		pages: BoundedVec<BoundedVec<u8, T::MaxPageLength>, T::MaxPageNum>,
+		pub price_asset: AssetKind<AssetIdOf<T>>,
+		pub transfer_policy: TransferPolicy,
+		pub sealed: bool,
+		/// The hash of each page in `PageStore`, or `None` for a page without bytes on chain.
+		pub(crate) pages: BoundedVec<Option<T::Hash>, T::MaxPageNum>,
+	}
+
+	/// A letter minted at genesis, as written in the chain spec.
+	///
+	/// The owner reserves the deposits of the letter and of each page. When `id` is set, genesis
+	/// fails unless it is the id the letter gets from `Pallet::letter_id_of`.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase", deny_unknown_fields))]
+	pub struct GenesisLetter<AccountId, Balance, Hash> {
+		pub title: Vec<u8>,
+		pub author: Vec<u8>,
+		pub pages: Vec<Vec<u8>>,
+		pub owner: AccountId,
+		pub price: Balance,
+		pub sealed: bool,
+		#[cfg_attr(feature = "std", serde(default))]
+		pub id: Option<Hash>,
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum TransferPolicy {
+		/// The letter can be transferred and sold.
+		Transferable,
+		/// The letter stays with its first owner until it is burned.
+		Soulbound,
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum AssetKind<AssetId> {
+		/// The native currency.
+		Native,
+		/// An asset of `Config::Assets`.
+		Asset(AssetId),
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum Role {
+		/// May write pages.
+		Writer,
+		/// May write pages and manage the writers of a letter.
+		Editor,
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum Freezer {
+		/// Frozen by the owner, who may thaw it again.
+		Owner,
+		/// Frozen by `Config::ForceOrigin`, which is the only one able to thaw it.
+		Force,
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum DepositAction {
+		/// The deposit is returned to whoever holds it.
+		Refund,
+		/// The deposit is slashed to `Config::Slash`.
+		Slash,
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ReportReason {
+		Illegal,
+		Abusive,
+		Spam,
+		Copyright,
+		Other,
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct DepositParams<Balance> {
+		/// Reserved for starting a letter.
+		pub letter_base: Balance,
+		/// Reserved per byte in the title and author of a letter.
+		pub letter_factor: Balance,
+		/// Reserved for adding a page.
+		pub page_base: Balance,
+		/// Reserved per byte in a page.
+		pub page_factor: Balance,
+	}
+
+	/// Identifies a page stored off chain. With the blake2b-256 multihash of `digest`, `codec`
+	/// gives the CIDv1 of the page, so it can be fetched from IPFS.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ContentId {
+		/// The multicodec of the page, such as `0x55` for raw bytes.
+		pub codec: u64,
+		/// The blake2b-256 hash of the page.
+		pub digest: [u8; 32],
+		/// The length of the page in bytes.
+		pub len: u32,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum Page<Bytes> {
+		/// The bytes of a page stored on chain.
+		OnChain(Bytes),
+		/// A page stored off chain, of which only the content identifier is kept.
+		OffChain(ContentId),
+		/// A page taken down by `Config::ModerationOrigin`, the reason of which is kept in
+		/// `RedactedPages`.
+		Redacted,
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum Compression {
+		/// An LZ4 block, without the length of the page in front of it.
+		Lz4,
+	}
+
+	/// The bytes of a page, shared by every letter with the same page. Each page referring to
+	/// them pays its own deposit, recorded in `PageDeposits`.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct StoredPage<T: Config> {
+		pub bytes: BoundedVec<u8, T::MaxPageLength>,
+		/// The number of pages in letters referring to these bytes.
+		pub refs: u32,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Report<T: Config> {
+		pub reporter: T::AccountId,
+		pub reason: ReportReason,
+		pub details: T::Hash,
+		pub bond: BalanceOf<T>,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Fraction<T: Config> {
+		pub asset_id: AssetIdOf<T>,
+		pub shares: AssetBalanceOf<T>,
+		pub depositor: T::AccountId,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Edition<T: Config> {
+		pub max_supply: u32,
+		pub print_price: T::Balance,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Collection<T: Config> {
+		pub id: u64,
+		pub curator: T::AccountId,
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		pub description: BoundedVec<u8, T::MaxDescriptionLength>,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Chapter<T: Config> {
+		pub letter_id: T::Hash,
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		pub deposit: BalanceOf<T>,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Book<T: Config> {
+		pub id: u64,
+		pub owner: T::AccountId,
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		pub price: T::Balance,
+		pub chapters: BoundedVec<Chapter<T>, T::MaxChapters>,
 	}
 
 	#[pallet::config]
@@ -46,6 +250,32 @@ pub mod pallet {
 		type Currency: ReservableCurrency<Self::AccountId>;
 		type WeightInfo: WeightInfo;
 
+		/// The fungible assets in which shares of a fractionalized letter are issued.
+		/// Letters can also be priced in these assets.
+		type Assets: Create<Self::AccountId>
+			+ Destroy<Self::AccountId>
+			+ Mutate<Self::AccountId>
+			+ Transfer<Self::AccountId>;
+
+		/// The origin allowed to freeze and thaw any letter, and to repair storage.
+		type ForceOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin allowed to take down abusive pages and letters.
+		type ModerationOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin allowed to change the deposits charged for letters and pages.
+		type ParameterOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Handler for the deposits slashed by moderation.
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Handler for the rent collected from letters.
+		type Rent: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The pallet id, used to derive the account holding fractionalized letters.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
 		/// The base amount of currency needed to reserve for starting a letter.
 		#[pallet::constant]
 		type LetterDepositBase: Get<BalanceOf<Self>>;
@@ -73,12 +303,91 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type MaxPageNum: Get<u32>;
+
+		#[pallet::constant]
+		type MaxDescriptionLength: Get<u32>;
+
+		/// The maximum number of letters in a collection.
+		#[pallet::constant]
+		type MaxCollectionSize: Get<u32>;
+
+		/// The maximum number of entries awaiting consent in a collection.
+		#[pallet::constant]
+		type MaxPendingEntries: Get<u32>;
+
+		/// The maximum number of collections a letter may be in or proposed to at once.
+		#[pallet::constant]
+		type MaxLetterEntries: Get<u32>;
+
+		/// The maximum number of chapters in a book.
+		#[pallet::constant]
+		type MaxChapters: Get<u32>;
+
+		/// The maximum number of contributors invited to a letter.
+		#[pallet::constant]
+		type MaxContributors: Get<u32>;
+
+		/// The amount of currency reserved from a reporter for each report.
+		#[pallet::constant]
+		type ReportBond: Get<BalanceOf<Self>>;
+
+		/// The maximum number of pending reports on a letter.
+		#[pallet::constant]
+		type MaxReports: Get<u32>;
+
+		/// The number of pending reports after which a letter is hidden from sale.
+		#[pallet::constant]
+		type HideThreshold: Get<u32>;
+
+		/// The rent charged per block for each byte in the pages of a letter. Zero disables rent.
+		#[pallet::constant]
+		type RentPerByte: Get<BalanceOf<Self>>;
+
+		/// The number of blocks the rent of a letter may stay unpaid before it is archived.
+		#[pallet::constant]
+		type RentGracePeriod: Get<Self::BlockNumber>;
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
+	// Domain separator hashed together with the sequence number of every letter id.
+	const LETTER_ID_DOMAIN: [u8; 8] = *b"letterid";
+
+	// Domain separator hashed together with a letter id to derive the asset of its shares.
+	const FRACTION_ID_DOMAIN: [u8; 8] = *b"fraction";
+
+	// Prefix of the offchain database keys under which indexed pages are stored.
+	const INDEXED_PAGE_PREFIX: [u8; 12] = *b"letters:page";
+
+	// The multicodec of raw bytes, used for the content identifier of indexed pages.
+	const RAW_CODEC: u64 = 0x55;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub (super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+				.saturating_add(crate::migrations::v2::migrate::<T>())
+				.saturating_add(crate::migrations::v3::migrate::<T>())
+				.saturating_add(crate::migrations::v4::migrate::<T>())
+				.saturating_add(crate::migrations::v5::migrate::<T>())
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::settle_rents(remaining_weight)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+	}
+
+	// The number of Letters and prints ever minted, from which the next id is derived.
 	#[pallet::storage]
 	#[pallet::getter(fn get_nonce)]
 	pub(super) type Nonce<T: Config> = StorageValue<_, u64, ValueQuery>;
@@ -89,6 +398,28 @@ pub mod pallet {
 	pub(super) type Letters<T: Config> =
 		StorageMap<_, Twox64Concat, T::Hash, (Letter<T>, BalanceOf<T>)>;
 
+	#[pallet::type_value]
+	pub(super) fn DefaultDepositParameters<T: Config>() -> DepositParams<BalanceOf<T>> {
+		DepositParams {
+			letter_base: T::LetterDepositBase::get(),
+			letter_factor: T::LetterDepositFactor::get(),
+			page_base: T::PageDepositBase::get(),
+			page_factor: T::PageDepositFactor::get(),
+		}
+	}
+
+	// The deposits charged for letters and pages, starting from the deposit constants.
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_parameters)]
+	pub(super) type DepositParameters<T: Config> =
+		StorageValue<_, DepositParams<BalanceOf<T>>, ValueQuery, DefaultDepositParameters<T>>;
+
+	// The account holding the reserve of a Letter. It stays with whoever locked the Letter in
+	// the pallet account when it is fractionalized.
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_holder)]
+	pub(super) type DepositHolder<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::AccountId>;
+
 	// Keeps track of what accounts own what Letter.
 	#[pallet::storage]
 	#[pallet::getter(fn owner_of)]
@@ -110,11 +441,10 @@ pub mod pallet {
 	pub(super) type AllLettersIndex<T: Config> =
 		StorageMap<_, Twox64Concat, T::Hash, u64, ValueQuery>;
 
-	// Keep track of who a Letter is owned by.
+	// Keeps track of the Letters owned by each account.
 	#[pallet::storage]
-	#[pallet::getter(fn letter_of_owner_by_index)]
-	pub(super) type OwnedLettersArray<T: Config> =
-		StorageMap<_, Twox64Concat, (T::AccountId, u64), T::Hash, ValueQuery>;
+	pub(super) type OwnedLetters<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::Hash, ()>;
 
 	// Keeps track of the total amount of Letters owned.
 	#[pallet::storage]
@@ -122,14 +452,210 @@ pub mod pallet {
 	pub(super) type OwnedLettersCount<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, u64, ValueQuery>;
 
-	// Keeps track of all owned Letters by index.
+	// Stores the Edition of a master Letter.
 	#[pallet::storage]
-	pub(super) type OwnedLettersIndex<T: Config> =
-		StorageMap<_, Twox64Concat, T::Hash, u64, ValueQuery>;
+	#[pallet::getter(fn edition)]
+	pub(super) type Editions<T: Config> = StorageMap<_, Twox64Concat, T::Hash, Edition<T>>;
+
+	// Keeps track of how many prints were minted from a master Letter.
+	#[pallet::storage]
+	#[pallet::getter(fn edition_supply)]
+	pub(super) type EditionSupply<T: Config> =
+		StorageMap<_, Twox64Concat, T::Hash, u32, ValueQuery>;
+
+	// Keeps track of how many prints of a master Letter are left, once burned prints are gone.
+	#[pallet::storage]
+	#[pallet::getter(fn live_prints)]
+	pub(super) type LivePrints<T: Config> = StorageMap<_, Twox64Concat, T::Hash, u32, ValueQuery>;
+
+	// Maps a print to its master Letter and print number.
+	#[pallet::storage]
+	#[pallet::getter(fn print_of)]
+	pub(super) type Prints<T: Config> = StorageMap<_, Twox64Concat, T::Hash, (T::Hash, u32)>;
+
+	// Stores a Collection and its reserve.
+	#[pallet::storage]
+	#[pallet::getter(fn collection)]
+	pub(super) type Collections<T: Config> =
+		StorageMap<_, Twox64Concat, u64, (Collection<T>, BalanceOf<T>)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collections_count)]
+	pub(super) type CollectionsCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	// The ordered Letters of a Collection.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_entries)]
+	pub(super) type CollectionEntries<T: Config> =
+		StorageMap<_, Twox64Concat, u64, BoundedVec<T::Hash, T::MaxCollectionSize>, ValueQuery>;
+
+	// Keeps track of the Collections a Letter belongs to.
+	#[pallet::storage]
+	pub(super) type LetterCollections<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, u64, ()>;
+
+	// Entries consented by either the curator or the letter owner, awaiting the other party.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_entry)]
+	pub(super) type PendingEntries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u64, Twox64Concat, T::Hash, T::AccountId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_entry_count)]
+	pub(super) type PendingEntryCount<T: Config> =
+		StorageMap<_, Twox64Concat, u64, u32, ValueQuery>;
+
+	// Keeps track of the Collections a Letter awaits consent for.
+	#[pallet::storage]
+	pub(super) type LetterPendingEntries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, u64, ()>;
+
+	// Counts the Collections a Letter belongs to or awaits consent for.
+	#[pallet::storage]
+	#[pallet::getter(fn letter_entry_count)]
+	pub(super) type LetterEntryCount<T: Config> =
+		StorageMap<_, Twox64Concat, T::Hash, u32, ValueQuery>;
+
+	// Stores a Book: its table of contents, price and reserve.
+	#[pallet::storage]
+	#[pallet::getter(fn book)]
+	pub(super) type Books<T: Config> = StorageMap<_, Twox64Concat, u64, (Book<T>, BalanceOf<T>)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn books_count)]
+	pub(super) type BooksCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	// Keeps track of what Book a Letter is a chapter of.
+	#[pallet::storage]
+	#[pallet::getter(fn chapter_of)]
+	pub(super) type ChapterOf<T: Config> = StorageMap<_, Twox64Concat, T::Hash, u64>;
+
+	// Keeps track of the accounts invited to write pages on a Letter.
+	#[pallet::storage]
+	#[pallet::getter(fn contributors)]
+	pub(super) type Contributors<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::Hash,
+		BoundedVec<(T::AccountId, Role), T::MaxContributors>,
+		ValueQuery,
+	>;
+
+	// Keeps track of what account wrote each page of a Letter.
+	#[pallet::storage]
+	#[pallet::getter(fn page_writer)]
+	pub(super) type PageWriter<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, u32, T::AccountId>;
+
+	// Page deposits held by contributors other than the Letter owner.
+	#[pallet::storage]
+	#[pallet::getter(fn contributor_deposit)]
+	pub(super) type ContributorDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::Hash,
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	// Shares of the primary sale of a Letter paid to its co-creators. The primary sale is the
+	// first sale, even if the Letter was given away before it, so gifts cannot dodge the split.
+	#[pallet::storage]
+	#[pallet::getter(fn revenue_split)]
+	pub(super) type RevenueSplits<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::Hash,
+		BoundedVec<(T::AccountId, Perbill), T::MaxContributors>,
+		ValueQuery,
+	>;
+
+	// Keeps track of the Letters locked in the pallet account and their shares.
+	#[pallet::storage]
+	#[pallet::getter(fn fraction)]
+	pub(super) type Fractionalized<T: Config> = StorageMap<_, Twox64Concat, T::Hash, Fraction<T>>;
+
+	// The sequence number of the next asset in which the shares of a Letter are issued.
+	#[pallet::storage]
+	pub(super) type FractionNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	// Keeps track of the frozen Letters and who froze them.
+	#[pallet::storage]
+	#[pallet::getter(fn frozen)]
+	pub(super) type Frozen<T: Config> = StorageMap<_, Twox64Concat, T::Hash, Freezer>;
+
+	// The bytes of the pages of every Letter, by the hash of the bytes, so identical pages are only
+	// stored once.
+	#[pallet::storage]
+	#[pallet::getter(fn page_store)]
+	pub(super) type PageStore<T: Config> = StorageMap<_, Twox64Concat, T::Hash, StoredPage<T>>;
+
+	// The deposit of every page referring to stored bytes, and who holds it, by the hash of the
+	// bytes and the letter and index of the page.
+	#[pallet::storage]
+	#[pallet::getter(fn page_deposit)]
+	pub(super) type PageDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::Hash,
+		Twox64Concat,
+		(T::Hash, u32),
+		(T::AccountId, BalanceOf<T>),
+	>;
+
+	// The content identifiers of the pages stored off chain. They have no bytes on chain.
+	#[pallet::storage]
+	#[pallet::getter(fn offchain_page)]
+	pub(super) type OffchainPages<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, u32, ContentId>;
+
+	// The compression of the pages stored compressed on chain. Other pages are stored as they are.
+	#[pallet::storage]
+	#[pallet::getter(fn compressed_page)]
+	pub(super) type CompressedPages<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, u32, Compression>;
+
+	// Keeps the tombstones of redacted pages, with the hash of the reason they were taken down.
+	#[pallet::storage]
+	#[pallet::getter(fn redacted_page)]
+	pub(super) type RedactedPages<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, u32, T::Hash>;
+
+	// Keeps track of the pending reports on a Letter.
+	#[pallet::storage]
+	#[pallet::getter(fn reports)]
+	pub(super) type Reports<T: Config> =
+		StorageMap<_, Twox64Concat, T::Hash, BoundedVec<Report<T>, T::MaxReports>, ValueQuery>;
+
+	// Letters with enough pending reports are hidden from sale.
+	#[pallet::storage]
+	#[pallet::getter(fn is_hidden)]
+	pub(super) type Hidden<T: Config> = StorageMap<_, Twox64Concat, T::Hash, bool, ValueQuery>;
+
+	// The block up to which the rent of a Letter is paid.
+	#[pallet::storage]
+	#[pallet::getter(fn rent_paid_until)]
+	pub(super) type RentPaidUntil<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::BlockNumber>;
+
+	// The number of bytes a Letter keeps on chain, which its rent is charged for.
+	#[pallet::storage]
+	#[pallet::getter(fn letter_bytes)]
+	pub(super) type LetterBytes<T: Config> = StorageMap<_, Twox64Concat, T::Hash, u32, ValueQuery>;
+
+	// Keeps the hash of the pages of an archived Letter, which restoring it has to match.
+	#[pallet::storage]
+	#[pallet::getter(fn archived)]
+	pub(super) type Archived<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::Hash>;
+
+	// The index in `AllLettersArray` of the last Letter whose rent was settled in `on_idle`.
+	#[pallet::storage]
+	pub(super) type RentCursor<T: Config> = StorageValue<_, u64, ValueQuery>;
 
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
-		pub letters: Vec<(Vec<u8>, Vec<u8>, Vec<u8>, T::AccountId, T::Hash, T::Balance)>,
+		pub letters: Vec<GenesisLetter<T::AccountId, T::Balance, T::Hash>>,
 	}
 
 	#[cfg(feature = "std")]
@@ -142,28 +668,10 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		fn build(&self) {
-			for (title, author, page, acct, hash, balance) in &self.letters {
-				let bounded_title: BoundedVec<u8, T::MaxTitleLength> =
-					title.clone().try_into().map_err(|()| Error::<T>::TitleLenOverflow).unwrap();
-				let bounded_author: BoundedVec<u8, T::MaxAuthorLength> =
-					author.clone().try_into().map_err(|()| Error::<T>::AuthorLenOverflow).unwrap();
-				let bounded_page: BoundedVec<u8, T::MaxPageLength> =
-					page.clone().try_into().map_err(|()| Error::<T>::PageLenOverflow).unwrap();
-
-				let mut pages = Vec::new();
-				pages.push(bounded_page);
-				let bounded_pages: BoundedVec<BoundedVec<u8, T::MaxPageLength>, T::MaxPageNum> =
-					pages.try_into().map_err(|()| Error::<T>::PageLenOverflow).unwrap();
-
-				let l = Letter {
-					id: hash.clone(),
-					title: bounded_title,
-					author: bounded_author,
-					price: balance.clone(),
-					pages: bounded_pages,
-				};
-
-				let _ = <Pallet<T>>::mint_letter(acct.clone(), hash.clone(), l);
+			for (index, letter) in self.letters.iter().enumerate() {
+				if let Err(e) = <Pallet<T>>::mint_genesis_letter(letter) {
+					panic!("Invalid genesis letter {}: {}", index, e);
+				}
 			}
 		}
 	}
@@ -173,9 +681,52 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		LetterInit(T::AccountId, T::Hash),
 		PageWritten(T::AccountId, T::Hash),
-		PriceSet(T::AccountId, T::Hash, T::Balance),
+		PriceSet(T::AccountId, T::Hash, T::Balance, AssetKind<AssetIdOf<T>>),
 		Transferred(T::AccountId, T::AccountId, T::Hash),
 		Bought(T::AccountId, T::AccountId, T::Hash, T::Balance),
+		EditionCreated(T::AccountId, T::Hash, u32, T::Balance),
+		PrintMinted(T::AccountId, T::Hash, T::Hash, u32),
+		CollectionCreated(T::AccountId, u64),
+		CollectionEntryProposed(T::AccountId, u64, T::Hash),
+		AddedToCollection(T::AccountId, u64, T::Hash),
+		RemovedFromCollection(T::AccountId, u64, T::Hash),
+		CollectionEntryMoved(T::AccountId, u64, T::Hash, u32),
+		CollectionDestroyed(T::AccountId, u64),
+		BookCreated(T::AccountId, u64),
+		ChapterAdded(T::AccountId, u64, T::Hash),
+		ChapterDetached(T::AccountId, u64, T::Hash),
+		BookPriceSet(T::AccountId, u64, T::Balance),
+		BookTransferred(T::AccountId, T::AccountId, u64),
+		BookBought(T::AccountId, T::AccountId, u64, T::Balance),
+		BookDestroyed(T::AccountId, u64),
+		ContributorInvited(T::AccountId, T::Hash, T::AccountId, Role),
+		ContributorRevoked(T::AccountId, T::Hash, T::AccountId),
+		Sealed(T::AccountId, T::Hash),
+		RevenueSplitSet(T::AccountId, T::Hash),
+		RevenueSplit(T::Hash, Vec<(T::AccountId, T::Balance)>),
+		Fractionalized(T::AccountId, T::Hash, AssetIdOf<T>, AssetBalanceOf<T>),
+		Redeemed(T::AccountId, T::Hash, AssetIdOf<T>),
+		Burned(T::AccountId, T::Hash),
+		LetterFrozen(T::Hash, Freezer),
+		LetterThawed(T::Hash),
+		PageRedacted(T::Hash, u32, T::Hash, BalanceOf<T>, DepositAction),
+		LetterTakenDown(T::AccountId, T::Hash, T::Hash, BalanceOf<T>, DepositAction),
+		LetterReported(T::AccountId, T::Hash, ReportReason, T::Hash),
+		ReportResolved(T::Hash, T::AccountId, DepositAction),
+		ListingHidden(T::Hash),
+		ListingRestored(T::Hash),
+		DepositSet(T::Hash, BalanceOf<T>),
+		OwnerIndexRebuilt(T::AccountId, u64),
+		PagesWritten(T::AccountId, T::Hash, u32),
+		PageAppended(T::AccountId, T::Hash, u32, u32),
+		DepositParametersSet(DepositParams<BalanceOf<T>>),
+		DepositRecomputed(T::Hash, BalanceOf<T>),
+		RentCollected(T::Hash, T::AccountId, BalanceOf<T>),
+		LetterArchived(T::Hash, T::Hash),
+		LetterRestored(T::AccountId, T::Hash),
+		OffchainPageWritten(T::AccountId, T::Hash, u32),
+		PageIndexed(T::AccountId, T::Hash, u32),
+		CompressedPageWritten(T::AccountId, T::Hash, u32, Compression),
 	}
 
 	#[pallet::error]
@@ -190,6 +741,61 @@ pub mod pallet {
 		AuthorLenOverflow,
 		PageCountOverflow,
 		LetterNotOwned,
+		LetterSealed,
+		EditionAlreadyExists,
+		NonExistentEdition,
+		EditionSupplyExhausted,
+		CannotEditionPrint,
+		DescriptionLenOverflow,
+		CollectionCountOverflow,
+		NonExistentCollection,
+		NotCurator,
+		NotCuratorOrOwner,
+		AlreadyInCollection,
+		NotInCollection,
+		CollectionFull,
+		TooManyPendingEntries,
+		InvalidPosition,
+		BookCountOverflow,
+		NonExistentBook,
+		BookNotOwned,
+		ChapterCountOverflow,
+		AlreadyChapter,
+		NotChapter,
+		ChapterNotDetached,
+		BookNotForSale,
+		BookOutOfBudget,
+		CannotBuyOwnBook,
+		AlreadyContributor,
+		NotContributor,
+		ContributorCountOverflow,
+		CannotManageContributor,
+		SplitCountOverflow,
+		SplitExceedsTotal,
+		LetterFractionalized,
+		NotFractionalized,
+		ZeroShares,
+		NotAllShares,
+		LetterSoulbound,
+		MasterHasPrints,
+		LetterFrozen,
+		LetterNotFrozen,
+		FrozenByForceOrigin,
+		PageRedacted,
+		AlreadyReported,
+		ReportCountOverflow,
+		NonExistentReport,
+		LetterHidden,
+		BadWitness,
+		RentUnpaid,
+		LetterArchived,
+		NotArchived,
+		ContentMismatch,
+		InvalidCompression,
+		PriceConversionOverflow,
+		NothingToAppend,
+		TooManyLetterEntries,
+		CannotTransferToSelf,
 	}
 
 	// Dispatchable functions
@@ -200,6 +806,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			title: Vec<u8>,
 			author: Vec<u8>,
+			transfer_policy: TransferPolicy,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
@@ -209,10 +816,10 @@ pub mod pallet {
 			let bounded_author: BoundedVec<u8, T::MaxAuthorLength> =
 				author.try_into().map_err(|()| Error::<T>::AuthorLenOverflow)?;
 
-			let letter_id = Self::letter_id(&sender, bounded_title.clone(), bounded_author.clone());
+			let letter_id = Self::next_letter_id()?;
 
-			let pages: Vec<BoundedVec<u8, T::MaxPageLength>> = Vec::new();
-			let bounded_pages: BoundedVec<BoundedVec<u8, T::MaxPageLength>, T::MaxPageNum> =
+			let pages: Vec<Option<T::Hash>> = Vec::new();
+			let bounded_pages: BoundedVec<Option<T::Hash>, T::MaxPageNum> =
 				pages.try_into().map_err(|()| Error::<T>::PageLenOverflow)?;
 
 			let letter = Letter {
@@ -220,11 +827,13 @@ pub mod pallet {
 				title: bounded_title,
 				author: bounded_author,
 				price: 0u8.into(),
+				price_asset: AssetKind::Native,
+				transfer_policy,
+				sealed: false,
 				pages: bounded_pages,
 			};
 
 			Self::mint_letter(sender, letter_id, letter)?;
-			Self::increment_nonce()?;
 
 			Ok(())
 		}
@@ -246,11 +855,21 @@ pub mod pallet {
 				None => return Err(Error::<T>::NonExistentLetter.into()),
 			};
 
+			ensure!(!letter.sealed, Error::<T>::LetterSealed);
+			ensure!(!<Frozen<T>>::contains_key(letter_id), Error::<T>::LetterFrozen);
+			ensure!(
+				!<Fractionalized<T>>::contains_key(letter_id),
+				Error::<T>::LetterFractionalized
+			);
+			ensure!(!<Archived<T>>::contains_key(letter_id), Error::<T>::LetterArchived);
+
 			let page_count = letter.pages.len();
 			if page_count == T::MaxPageNum::get() as usize {
 				return Err(Error::<T>::PageCountOverflow.into())
 			}
 
+			Self::ensure_rent_paid(letter_id)?;
+
 			Self::mint_page(sender.clone(), letter_id, page)?;
 
 			Self::deposit_event(Event::PageWritten(sender, letter_id));
@@ -258,17 +877,199 @@ pub mod pallet {
 			Ok(())
 		}
 
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::write_pages(pages.len() as u32, pages.iter().map(|page| page.len() as u32).sum()))]
+		#[transactional]
+		pub fn write_pages(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			pages: BoundedVec<BoundedVec<u8, T::MaxPageLength>, T::MaxPageNum>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (letter, _) = Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(!letter.sealed, Error::<T>::LetterSealed);
+			ensure!(!<Frozen<T>>::contains_key(letter_id), Error::<T>::LetterFrozen);
+			ensure!(
+				!<Fractionalized<T>>::contains_key(letter_id),
+				Error::<T>::LetterFractionalized
+			);
+			ensure!(!<Archived<T>>::contains_key(letter_id), Error::<T>::LetterArchived);
+			Self::ensure_rent_paid(letter_id)?;
+
+			let count = pages.len() as u32;
+			Self::mint_pages(sender.clone(), letter_id, pages)?;
+
+			Self::deposit_event(Event::PagesWritten(sender, letter_id, count));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::append_to_page(bytes.len() as u32))]
+		#[transactional]
+		pub fn append_to_page(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			bytes: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(!bytes.is_empty(), Error::<T>::NothingToAppend);
+			ensure!(bytes.len() <= T::MaxPageLength::get() as usize, Error::<T>::PageLenOverflow);
+
+			let (letter, _) = Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(!letter.sealed, Error::<T>::LetterSealed);
+			ensure!(!<Frozen<T>>::contains_key(letter_id), Error::<T>::LetterFrozen);
+			ensure!(
+				!<Fractionalized<T>>::contains_key(letter_id),
+				Error::<T>::LetterFractionalized
+			);
+			ensure!(!<Archived<T>>::contains_key(letter_id), Error::<T>::LetterArchived);
+			Self::ensure_rent_paid(letter_id)?;
+
+			let (first_index, last_index) =
+				Self::append_page_bytes(sender.clone(), letter_id, bytes)?;
+
+			Self::deposit_event(Event::PageAppended(sender, letter_id, first_index, last_index));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::write_offchain_page())]
+		pub fn write_offchain_page(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			content: ContentId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (letter, _) = Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(!letter.sealed, Error::<T>::LetterSealed);
+			ensure!(!<Frozen<T>>::contains_key(letter_id), Error::<T>::LetterFrozen);
+			ensure!(
+				!<Fractionalized<T>>::contains_key(letter_id),
+				Error::<T>::LetterFractionalized
+			);
+			ensure!(!<Archived<T>>::contains_key(letter_id), Error::<T>::LetterArchived);
+			ensure!(
+				letter.pages.len() < T::MaxPageNum::get() as usize,
+				Error::<T>::PageCountOverflow
+			);
+			Self::ensure_rent_paid(letter_id)?;
+
+			let index = Self::mint_offchain_page(sender.clone(), letter_id, content)?;
+
+			Self::deposit_event(Event::OffchainPageWritten(sender, letter_id, index));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::write_indexed_page(page.len() as u32))]
+		pub fn write_indexed_page(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			page: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(page.len() <= T::MaxPageLength::get() as usize, Error::<T>::PageLenOverflow);
+
+			let (letter, _) = Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(!letter.sealed, Error::<T>::LetterSealed);
+			ensure!(!<Frozen<T>>::contains_key(letter_id), Error::<T>::LetterFrozen);
+			ensure!(
+				!<Fractionalized<T>>::contains_key(letter_id),
+				Error::<T>::LetterFractionalized
+			);
+			ensure!(!<Archived<T>>::contains_key(letter_id), Error::<T>::LetterArchived);
+			ensure!(
+				letter.pages.len() < T::MaxPageNum::get() as usize,
+				Error::<T>::PageCountOverflow
+			);
+			Self::ensure_rent_paid(letter_id)?;
+
+			// The bytes only go to the offchain database of nodes with offchain indexing
+			// enabled, the chain keeps their hash like any other page stored off chain.
+			let content = ContentId {
+				codec: RAW_CODEC,
+				digest: sp_io::hashing::blake2_256(&page),
+				len: page.len() as u32,
+			};
+			let index = Self::mint_offchain_page(sender.clone(), letter_id, content)?;
+			sp_io::offchain_index::set(&Self::indexed_page_key(letter_id, index), &page);
+
+			Self::deposit_event(Event::PageIndexed(sender, letter_id, index));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::write_compressed_page(page.len() as u32).saturating_add(<T as pallet::Config>::WeightInfo::decompress_page(T::MaxPageLength::get())))]
+		pub fn write_compressed_page(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			page: Vec<u8>,
+			compression: Compression,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(page.len() <= T::MaxPageLength::get() as usize, Error::<T>::PageLenOverflow);
+
+			let (letter, _) = Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(!letter.sealed, Error::<T>::LetterSealed);
+			ensure!(!<Frozen<T>>::contains_key(letter_id), Error::<T>::LetterFrozen);
+			ensure!(
+				!<Fractionalized<T>>::contains_key(letter_id),
+				Error::<T>::LetterFractionalized
+			);
+			ensure!(!<Archived<T>>::contains_key(letter_id), Error::<T>::LetterArchived);
+			ensure!(
+				letter.pages.len() < T::MaxPageNum::get() as usize,
+				Error::<T>::PageCountOverflow
+			);
+			Self::ensure_rent_paid(letter_id)?;
+
+			// The page must be readable later on, so it is decompressed once here, which also
+			// makes sure it does not grow past `MaxPageLength`.
+			let decompressed = Self::decompress_page(compression, &page)?.len() as u32;
+
+			// the deposit is charged on the compressed bytes, which are the ones stored
+			let compressed = page.len() as u32;
+			let index = letter.pages.len() as u32;
+			Self::mint_page(sender.clone(), letter_id, page)?;
+			<CompressedPages<T>>::insert(letter_id, index, compression);
+
+			Self::deposit_event(Event::CompressedPageWritten(
+				sender,
+				letter_id,
+				index,
+				compression,
+			));
+
+			let decompress_weight =
+				<T as pallet::Config>::WeightInfo::decompress_page(decompressed);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::write_compressed_page(compressed)
+					.saturating_add(decompress_weight),
+			)
+			.into())
+		}
+
 		// set_price
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_price())]
 		pub fn set_price(
 			origin: OriginFor<T>,
 			letter_id: T::Hash,
 			new_price: T::Balance,
+			price_asset: AssetKind<AssetIdOf<T>>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
 			// Make sure the letter exists.
 			ensure!(<Letters<T>>::contains_key(letter_id), "This letter does not exist");
+			ensure!(!<Frozen<T>>::contains_key(letter_id), Error::<T>::LetterFrozen);
+			ensure!(
+				!<Fractionalized<T>>::contains_key(letter_id),
+				Error::<T>::LetterFractionalized
+			);
 
 			// Check that the letter has an owner (i.e. if it exists).
 			let owner = Self::owner_of(letter_id).ok_or("No owner for this letter")?;
@@ -281,16 +1082,18 @@ pub mod pallet {
 				None => return Err(Error::<T>::NonExistentLetter.into()),
 			};
 			letter.price = new_price;
+			letter.price_asset = price_asset;
 
 			// Update new letter infomation to storage.
 			<Letters<T>>::insert(letter_id, (letter, reserve));
 
-			Self::deposit_event(Event::PriceSet(sender, letter_id, new_price));
+			Self::deposit_event(Event::PriceSet(sender, letter_id, new_price, price_asset));
 
 			Ok(().into())
 		}
 
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer())]
+		#[transactional]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			to: T::AccountId,
@@ -298,10 +1101,18 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
+			ensure!(
+				!<Fractionalized<T>>::contains_key(letter_id),
+				Error::<T>::LetterFractionalized
+			);
+
 			// Verify letter owner: must be the account invoking this transaction.
 			let owner = Self::owner_of(letter_id).ok_or("No owner for this letter")?;
 			ensure!(owner == sender, "You do not own this letter");
 
+			// Chapters only change hands together with their book.
+			ensure!(!<ChapterOf<T>>::contains_key(letter_id), Error::<T>::ChapterNotDetached);
+
 			// Transfer.
 			Self::transfer_from(sender, to, letter_id)?;
 
@@ -309,7 +1120,8 @@ pub mod pallet {
 		}
 
 		// buy_letter
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::buy_letter())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::buy_letter(T::MaxContributors::get()))]
+		#[transactional]
 		pub fn buy_letter(
 			origin: OriginFor<T>,
 			letter_id: T::Hash,
@@ -319,6 +1131,10 @@ pub mod pallet {
 
 			// Check if the letter exists.
 			ensure!(<Letters<T>>::contains_key(letter_id), "This letter does not exist");
+			ensure!(
+				!<Fractionalized<T>>::contains_key(letter_id),
+				Error::<T>::LetterFractionalized
+			);
 
 			// Check that the letter has an owner.
 			let owner = Self::owner_of(letter_id).ok_or("No owner for this letter")?;
@@ -326,66 +1142,1421 @@ pub mod pallet {
 			// Check that account buying the letter doesn't already own it.
 			ensure!(owner != sender, "You can't buy your own letter");
 
+			// Chapters are only sold together with their book.
+			ensure!(!<ChapterOf<T>>::contains_key(letter_id), Error::<T>::ChapterNotDetached);
+
 			// Get the price of the letter
-			let (mut letter, reserve) = match Self::letter(letter_id) {
+			let (letter, _) = match Self::letter(letter_id) {
 				Some((l, r)) => (l, r),
 				None => return Err(Error::<T>::NonExistentLetter.into()),
 			};
 			let letter_price = letter.price;
 
 			// Check if the letter is for sale.
+			ensure!(!Self::is_hidden(letter_id), Error::<T>::LetterHidden);
 			ensure!(!letter_price.is_zero(), "This letter is not for sale!");
 			// Check that the letter's current price is within buyers budget.
 			ensure!(letter_price <= ask_price, "This letter is out of your budget!");
+			// Soulbound letters never change hands, so nothing is paid for them.
+			ensure!(
+				letter.transfer_policy == TransferPolicy::Transferable,
+				Error::<T>::LetterSoulbound
+			);
 
-			// Update Balances using the Currency trait.
-			<pallet_balances::Pallet<T> as Currency<_>>::transfer(
-				&sender,
-				&owner,
-				letter_price,
-				ExistenceRequirement::KeepAlive,
-			)?;
+			// The primary sale is shared among the co-creators. The seller receives whatever is
+			// left after every share is paid, so rounding dust always ends up with the seller.
+			// So does a share too small to open the account of a co-creator who has none.
+			let split = <RevenueSplits<T>>::take(letter_id);
+			let shares = split.len() as u32;
+			let mut payouts = Vec::new();
+			let mut remainder = letter_price;
+			for (who, share) in split.into_iter() {
+				let amount = share.mul_floor(letter_price);
+				if Self::below_minimum(letter.price_asset, &who, amount) {
+					continue
+				}
+				remainder = remainder.saturating_sub(amount);
+				payouts.push((who, amount));
+			}
+			payouts.push((owner.clone(), remainder));
+
+			// Pay in the currency the letter is priced in.
+			for (who, amount) in payouts.iter() {
+				if amount.is_zero() || *who == sender {
+					continue
+				}
+				Self::pay(letter.price_asset, &sender, who, *amount)?;
+			}
+
+			if shares > 0 {
+				Self::deposit_event(Event::RevenueSplit(letter_id, payouts));
+			}
+
+			// Transfer ownership of letter.
+			Self::transfer_from(owner.clone(), sender.clone(), letter_id)?;
+
+			// Set the price of the letter to the new price it was sold at. The reserve may have
+			// changed hands with the letter, so only the price is written.
+			<Letters<T>>::mutate(letter_id, |letter| {
+				if let Some((letter, _)) = letter {
+					letter.price = ask_price.into();
+				}
+			});
+
+			Self::deposit_event(Event::Bought(sender, owner, letter_id, letter_price));
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::buy_letter(shares)).into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::fractionalize())]
+		#[transactional]
+		pub fn fractionalize(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			shares: AssetBalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let owner = Self::owner_of(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(owner == sender, Error::<T>::LetterNotOwned);
+			ensure!(!<ChapterOf<T>>::contains_key(letter_id), Error::<T>::ChapterNotDetached);
+			ensure!(!shares.is_zero(), Error::<T>::ZeroShares);
+
+			// The asset is created here, so its whole supply is made of the minted shares. It is
+			// not sufficient: holding shares does not keep an account alive.
+			let pallet_account = Self::account_id();
+			let asset_id = Self::next_fraction_asset_id(letter_id);
+			T::Assets::create(asset_id, pallet_account.clone(), false, One::one())?;
+			T::Assets::mint_into(asset_id, &sender, shares)?;
+
+			// Lock the letter in the pallet account.
+			Self::transfer_from(sender.clone(), pallet_account, letter_id)?;
+			<Fractionalized<T>>::insert(
+				letter_id,
+				Fraction { asset_id, shares, depositor: sender.clone() },
+			);
+
+			Self::deposit_event(Event::Fractionalized(sender, letter_id, asset_id, shares));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::redeem())]
+		#[transactional]
+		pub fn redeem(origin: OriginFor<T>, letter_id: T::Hash) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let fraction = Self::fraction(letter_id).ok_or(Error::<T>::NotFractionalized)?;
+
+			// Only the holder of every share can release the letter.
+			let held = T::Assets::balance(fraction.asset_id, &sender);
+			ensure!(
+				held == T::Assets::total_issuance(fraction.asset_id),
+				Error::<T>::NotAllShares
+			);
+			T::Assets::burn_from(fraction.asset_id, &sender, held)?;
+
+			// No share is left, so the asset goes with them.
+			let witness = T::Assets::get_destroy_witness(&fraction.asset_id)
+				.ok_or(Error::<T>::NotFractionalized)?;
+			T::Assets::destroy(fraction.asset_id, witness, None)?;
+
+			Self::transfer_from(Self::account_id(), sender.clone(), letter_id)?;
+			<Fractionalized<T>>::remove(letter_id);
+
+			Self::deposit_event(Event::Redeemed(sender, letter_id, fraction.asset_id));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::seal_letter())]
+		pub fn seal_letter(origin: OriginFor<T>, letter_id: T::Hash) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let owner = Self::owner_of(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(owner == sender, Error::<T>::LetterNotOwned);
+
+			let (mut letter, reserve) =
+				Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(!letter.sealed, Error::<T>::LetterSealed);
+
+			// No more pages can be written, and the revenue split is fixed.
+			letter.sealed = true;
+			<Letters<T>>::insert(letter_id, (letter, reserve));
+
+			Self::deposit_event(Event::Sealed(sender, letter_id));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_revenue_split(split.len() as u32))]
+		pub fn set_revenue_split(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			split: Vec<(T::AccountId, Perbill)>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let owner = Self::owner_of(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(owner == sender, Error::<T>::LetterNotOwned);
+
+			let (letter, _) = Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(!letter.sealed, Error::<T>::LetterSealed);
+
+			let total =
+				split.iter().fold(0u64, |total, (_, share)| total + share.deconstruct() as u64);
+			ensure!(total <= Perbill::one().deconstruct() as u64, Error::<T>::SplitExceedsTotal);
+
+			let bounded_split: BoundedVec<(T::AccountId, Perbill), T::MaxContributors> =
+				split.try_into().map_err(|_| Error::<T>::SplitCountOverflow)?;
+			<RevenueSplits<T>>::insert(letter_id, bounded_split);
+
+			Self::deposit_event(Event::RevenueSplitSet(sender, letter_id));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::burn_letter(
+			T::MaxPageNum::get(),
+			T::MaxLetterEntries::get(),
+		))]
+		pub fn burn_letter(origin: OriginFor<T>, letter_id: T::Hash) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			// Soulbound letters can still be burned by their owner.
+			let owner = Self::owner_of(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(owner == sender, Error::<T>::LetterNotOwned);
+			ensure!(!<ChapterOf<T>>::contains_key(letter_id), Error::<T>::ChapterNotDetached);
+			ensure!(!<Frozen<T>>::contains_key(letter_id), Error::<T>::LetterFrozen);
+
+			// Prints read their pages from the master.
+			ensure!(Self::live_prints(letter_id) == 0, Error::<T>::MasterHasPrints);
+
+			let (pages, entries) = Self::burn_weight_of(letter_id);
+			Self::burn_from(sender.clone(), letter_id, DepositAction::Refund)?;
+
+			Self::deposit_event(Event::Burned(sender, letter_id));
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::burn_letter(pages, entries)).into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::freeze())]
+		pub fn freeze(origin: OriginFor<T>, letter_id: T::Hash) -> DispatchResultWithPostInfo {
+			let freezer = Self::ensure_owner_or_force(origin, letter_id)?;
+			ensure!(!<Frozen<T>>::contains_key(letter_id), Error::<T>::LetterFrozen);
+
+			<Frozen<T>>::insert(letter_id, freezer);
+
+			Self::deposit_event(Event::LetterFrozen(letter_id, freezer));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::thaw())]
+		pub fn thaw(origin: OriginFor<T>, letter_id: T::Hash) -> DispatchResultWithPostInfo {
+			let thawer = Self::ensure_owner_or_force(origin, letter_id)?;
+			let freezer = Self::frozen(letter_id).ok_or(Error::<T>::LetterNotFrozen)?;
+
+			// An owner can not lift a freeze put in place by the force origin.
+			ensure!(
+				freezer == Freezer::Owner || thawer == Freezer::Force,
+				Error::<T>::FrozenByForceOrigin
+			);
+
+			<Frozen<T>>::remove(letter_id);
+
+			Self::deposit_event(Event::LetterThawed(letter_id));
+
+			Ok(().into())
+		}
+
+		/// Redact a page, along with every page in any letter sharing its bytes. `witness_pages`
+		/// must be at least the number of pages sharing them.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_redact_page(*witness_pages))]
+		#[transactional]
+		pub fn force_redact_page(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			index: u32,
+			reason: T::Hash,
+			action: DepositAction,
+			witness_pages: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ModerationOrigin::ensure_origin(origin)?;
+
+			let (letter, _) = Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(!<RedactedPages<T>>::contains_key(letter_id, index), Error::<T>::PageRedacted);
+			let page = letter.pages.get(index as usize).ok_or(Error::<T>::NonExistentPage)?;
+
+			let redacted = match page {
+				// The bytes are gone from every page referring to them, and each of those pages
+				// releases its own deposit.
+				Some(page_hash) => {
+					let stored = Self::page_store(page_hash).ok_or(Error::<T>::NonExistentPage)?;
+					ensure!(stored.refs <= witness_pages, Error::<T>::BadWitness);
+					let pages: Vec<_> = <PageDeposits<T>>::iter_key_prefix(page_hash).collect();
+					for (sharing_id, sharing_index) in pages.iter() {
+						Self::redact_page(*sharing_id, *sharing_index, reason, action)?;
+					}
+					pages.len() as u32
+				},
+				None => {
+					Self::redact_page(letter_id, index, reason, action)?;
+					1
+				},
+			};
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::force_redact_page(redacted)).into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_burn_letter(
+			T::MaxPageNum::get(),
+			T::MaxLetterEntries::get(),
+		))]
+		#[transactional]
+		pub fn force_burn_letter(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			reason: T::Hash,
+			action: DepositAction,
+		) -> DispatchResultWithPostInfo {
+			T::ModerationOrigin::ensure_origin(origin)?;
+
+			let owner = Self::owner_of(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+
+			// Prints read their pages from the master, so they are taken down first.
+			ensure!(Self::live_prints(letter_id) == 0, Error::<T>::MasterHasPrints);
+
+			// Take the letter out of its book, returning the chapter title deposit.
+			if let Some(book_id) = <ChapterOf<T>>::take(letter_id) {
+				if let Some((mut book, mut book_reserve)) = Self::book(book_id) {
+					if let Some(index) = book.chapters.iter().position(|c| c.letter_id == letter_id)
+					{
+						let chapter = book.chapters.remove(index);
+						T::Currency::unreserve(&book.owner, chapter.deposit);
+						book_reserve -= chapter.deposit;
+					}
+					<Books<T>>::insert(book_id, (book, book_reserve));
+				}
+			}
+
+			let (pages, entries) = Self::burn_weight_of(letter_id);
+			let released = Self::burn_from(owner.clone(), letter_id, action)?;
+
+			Self::deposit_event(Event::LetterTakenDown(owner, letter_id, reason, released, action));
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::force_burn_letter(pages, entries)).into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::report_letter())]
+		pub fn report_letter(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			reason_code: ReportReason,
+			details_hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(<Letters<T>>::contains_key(letter_id), Error::<T>::NonExistentLetter);
+
+			let mut reports = Self::reports(letter_id);
+			ensure!(!reports.iter().any(|r| r.reporter == sender), Error::<T>::AlreadyReported);
+
+			// The bond deters spam and is slashed if the report is dismissed.
+			let bond = T::ReportBond::get();
+			reports
+				.try_push(Report {
+					reporter: sender.clone(),
+					reason: reason_code,
+					details: details_hash,
+					bond,
+				})
+				.map_err(|_| Error::<T>::ReportCountOverflow)?;
+			T::Currency::reserve(&sender, bond)?;
+
+			let hide = reports.len() as u32 >= T::HideThreshold::get();
+			<Reports<T>>::insert(letter_id, reports);
+
+			Self::deposit_event(Event::LetterReported(
+				sender,
+				letter_id,
+				reason_code,
+				details_hash,
+			));
+
+			if hide && !Self::is_hidden(letter_id) {
+				<Hidden<T>>::insert(letter_id, true);
+				Self::deposit_event(Event::ListingHidden(letter_id));
+			}
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::resolve_report())]
+		pub fn resolve_report(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			reporter: T::AccountId,
+			action: DepositAction,
+		) -> DispatchResultWithPostInfo {
+			T::ModerationOrigin::ensure_origin(origin)?;
+
+			let mut reports = Self::reports(letter_id);
+			let index = reports
+				.iter()
+				.position(|r| r.reporter == reporter)
+				.ok_or(Error::<T>::NonExistentReport)?;
+			let report = reports.remove(index);
+
+			// The bond is refunded if the report is upheld and slashed if it is dismissed.
+			deposit::release::<T>(&report.reporter, report.bond, action);
+
+			let restore = (reports.len() as u32) < T::HideThreshold::get();
+			if reports.is_empty() {
+				<Reports<T>>::remove(letter_id);
+			} else {
+				<Reports<T>>::insert(letter_id, reports);
+			}
+
+			Self::deposit_event(Event::ReportResolved(letter_id, reporter, action));
+
+			if restore && Self::is_hidden(letter_id) {
+				<Hidden<T>>::remove(letter_id);
+				Self::deposit_event(Event::ListingRestored(letter_id));
+			}
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_transfer())]
+		#[transactional]
+		pub fn force_transfer(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			to: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let owner = Self::owner_of(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			let (_, reserve) = Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(owner != to, Error::<T>::CannotTransferToSelf);
+			ensure!(
+				!<Fractionalized<T>>::contains_key(letter_id),
+				Error::<T>::LetterFractionalized
+			);
+			ensure!(!<ChapterOf<T>>::contains_key(letter_id), Error::<T>::ChapterNotDetached);
+
+			// Soulbound and frozen letters can still be moved here.
+			Self::move_letter(owner, to, letter_id, reserve)?;
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_set_price())]
+		pub fn force_set_price(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			new_price: T::Balance,
+			price_asset: AssetKind<AssetIdOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let owner = Self::owner_of(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			let (mut letter, reserve) =
+				Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			letter.price = new_price;
+			letter.price_asset = price_asset;
+			<Letters<T>>::insert(letter_id, (letter, reserve));
+
+			Self::deposit_event(Event::PriceSet(owner, letter_id, new_price, price_asset));
+
+			Ok(().into())
+		}
+
+		/// Set the letter deposit held from the owner. The deposits contributors hold for their
+		/// off-chain pages and the deposits of stored pages are left untouched.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_set_deposit())]
+		pub fn force_set_deposit(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			deposit: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let (letter, mut reserve) =
+				Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+
+			// Only the difference with the recorded reserve is moved.
+			deposit::set::<T>(letter_id, &mut reserve, deposit)?;
+			<Letters<T>>::insert(letter_id, (letter, reserve));
+
+			Self::deposit_event(Event::DepositSet(letter_id, deposit));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_rebuild_owner_index(*witness_letters))]
+		pub fn force_rebuild_owner_index(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			witness_letters: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let all_letters_count = Self::all_letters_count();
+			ensure!(witness_letters as u64 >= all_letters_count, Error::<T>::BadWitness);
+
+			// Re-index the letters of the account from the owner of every letter.
+			let _ = <OwnedLetters<T>>::remove_prefix(&account, None);
+			let mut count: u64 = 0;
+			for all_index in 1..=all_letters_count {
+				let letter_id = Self::letter_by_index(all_index);
+				if Self::owner_of(letter_id).as_ref() == Some(&account) {
+					count += 1;
+					<OwnedLetters<T>>::insert(&account, letter_id, ());
+				}
+			}
+			<OwnedLettersCount<T>>::insert(&account, count);
+
+			Self::deposit_event(Event::OwnerIndexRebuilt(account, count));
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::force_rebuild_owner_index(
+				all_letters_count as u32,
+			))
+			.into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_deposit_parameters())]
+		pub fn set_deposit_parameters(
+			origin: OriginFor<T>,
+			parameters: DepositParams<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			T::ParameterOrigin::ensure_origin(origin)?;
+
+			// Existing letters keep their reserve until `recompute_deposit` is called on them.
+			<DepositParameters<T>>::put(parameters);
+
+			Self::deposit_event(Event::DepositParametersSet(parameters));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::recompute_deposit(T::MaxPageNum::get()))]
+		#[transactional]
+		pub fn recompute_deposit(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			// the pages of an archived letter are gone, but their deposits are still held
+			ensure!(!<Archived<T>>::contains_key(letter_id), Error::<T>::LetterArchived);
+
+			let (reserve, pages) = Self::recompute_deposits(letter_id)?;
+
+			Self::deposit_event(Event::DepositRecomputed(letter_id, reserve));
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::recompute_deposit(pages)).into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::collect_rent(T::MaxPageNum::get()))]
+		pub fn collect_rent(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let (letter, _) = Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			let pages = letter.pages.len() as u32;
+
+			// Letters whose rent cannot be paid are archived once the grace period is over.
+			Self::settle_rent(letter_id)?;
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::collect_rent(pages)).into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::restore_letter(pages.len() as u32, pages.iter().map(|page| page.len() as u32).sum()))]
+		#[transactional]
+		pub fn restore_letter(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			pages: BoundedVec<BoundedVec<u8, T::MaxPageLength>, T::MaxPageNum>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let content_hash = Self::archived(letter_id).ok_or(Error::<T>::NotArchived)?;
+			// The deposit of the letter was kept when it was archived, and its rent is charged to
+			// whoever holds it from now on. The page deposits refunded by the archive are charged
+			// again as the pages are stored anew.
+			ensure!(
+				Self::deposit_holder(letter_id) == Some(sender.clone()),
+				Error::<T>::LetterNotOwned
+			);
+			ensure!(T::Hashing::hash_of(&pages) == content_hash, Error::<T>::ContentMismatch);
+
+			let (mut letter, reserve) =
+				Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			let mut page_hashes = Vec::with_capacity(pages.len());
+			for (index, page) in pages.into_iter().enumerate() {
+				// pages stored off chain or redacted had no bytes to begin with
+				if page.is_empty() && Self::has_no_bytes(letter_id, index as u32) {
+					page_hashes.push(None);
+				} else {
+					let page_hash = Self::store_page(&sender, letter_id, index as u32, page)?;
+					page_hashes.push(Some(page_hash));
+				}
+			}
+			letter.pages = page_hashes.try_into().map_err(|_| Error::<T>::PageCountOverflow)?;
+			<Letters<T>>::insert(letter_id, (letter, reserve));
+			<Archived<T>>::remove(letter_id);
+			<RentPaidUntil<T>>::insert(letter_id, <frame_system::Pallet<T>>::block_number());
+
+			Self::deposit_event(Event::LetterRestored(sender, letter_id));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_edition())]
+		pub fn create_edition(
+			origin: OriginFor<T>,
+			master_id: T::Hash,
+			max_supply: u32,
+			print_price: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(<Letters<T>>::contains_key(master_id), Error::<T>::NonExistentLetter);
+
+			// Only the owner of the master letter can create an edition out of it.
+			let owner = Self::owner_of(master_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(owner == sender, Error::<T>::LetterNotOwned);
+
+			// Prints can't be used as masters of other editions.
+			ensure!(!<Prints<T>>::contains_key(master_id), Error::<T>::CannotEditionPrint);
+			ensure!(!<Editions<T>>::contains_key(master_id), Error::<T>::EditionAlreadyExists);
+
+			<Editions<T>>::insert(master_id, Edition { max_supply, print_price });
+
+			Self::deposit_event(Event::EditionCreated(sender, master_id, max_supply, print_price));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::mint_print())]
+		#[transactional]
+		pub fn mint_print(origin: OriginFor<T>, master_id: T::Hash) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let edition = Self::edition(master_id).ok_or(Error::<T>::NonExistentEdition)?;
+			let (mut master, master_reserve) =
+				Self::letter(master_id).ok_or(Error::<T>::NonExistentLetter)?;
+			let owner = Self::owner_of(master_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(!Self::is_hidden(master_id), Error::<T>::LetterHidden);
+			// the owner of a locked master is the pallet account, where the price would be lost
+			ensure!(
+				!<Fractionalized<T>>::contains_key(master_id),
+				Error::<T>::LetterFractionalized
+			);
+
+			// Prints are numbered starting from 1.
+			let supply = Self::edition_supply(master_id);
+			ensure!(supply < edition.max_supply, Error::<T>::EditionSupplyExhausted);
+			let number = supply + 1;
+
+			// Pay the print price to the owner of the master letter.
+			if !edition.print_price.is_zero() && owner != sender {
+				<pallet_balances::Pallet<T> as Currency<_>>::transfer(
+					&sender,
+					&owner,
+					edition.print_price,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			}
+
+			// A print carries no pages of its own: they are read from the master.
+			let print_id = Self::next_letter_id()?;
+			let pages: Vec<Option<T::Hash>> = Vec::new();
+			let print = Letter {
+				id: print_id,
+				title: master.title.clone(),
+				author: master.author.clone(),
+				price: 0u8.into(),
+				price_asset: AssetKind::Native,
+				transfer_policy: TransferPolicy::Transferable,
+				sealed: true,
+				pages: pages.try_into().map_err(|()| Error::<T>::PageCountOverflow)?,
+			};
+
+			Self::mint_letter(sender.clone(), print_id, print)?;
+			<Prints<T>>::insert(print_id, (master_id, number));
+			<EditionSupply<T>>::insert(master_id, number);
+			<LivePrints<T>>::mutate(master_id, |live| *live = live.saturating_add(1));
+
+			// The master can no longer be written once its first print is out.
+			if !master.sealed {
+				master.sealed = true;
+				<Letters<T>>::insert(master_id, (master, master_reserve));
+			}
+
+			Self::deposit_event(Event::PrintMinted(sender, master_id, print_id, number));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_collection(title.len() as u32, description.len() as u32))]
+		pub fn create_collection(
+			origin: OriginFor<T>,
+			title: Vec<u8>,
+			description: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let bounded_title: BoundedVec<u8, T::MaxTitleLength> =
+				title.try_into().map_err(|()| Error::<T>::TitleLenOverflow)?;
+
+			let bounded_description: BoundedVec<u8, T::MaxDescriptionLength> =
+				description.try_into().map_err(|()| Error::<T>::DescriptionLenOverflow)?;
+
+			let collection_id = Self::collections_count()
+				.checked_add(1)
+				.ok_or(Error::<T>::CollectionCountOverflow)?;
+
+			// reserve collection deposit
+			let params = <DepositParameters<T>>::get();
+			let reserve = params.letter_base +
+				params.letter_factor * (bounded_title.len() as u32).into() +
+				params.letter_factor * (bounded_description.len() as u32).into();
+			T::Currency::reserve(&sender, reserve)?;
+
+			let collection = Collection {
+				id: collection_id,
+				curator: sender.clone(),
+				title: bounded_title,
+				description: bounded_description,
+			};
+
+			<Collections<T>>::insert(collection_id, (collection, reserve));
+			<CollectionsCount<T>>::put(collection_id);
+
+			Self::deposit_event(Event::CollectionCreated(sender, collection_id));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_to_collection())]
+		pub fn add_to_collection(
+			origin: OriginFor<T>,
+			collection_id: u64,
+			letter_id: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (collection, _) =
+				Self::collection(collection_id).ok_or(Error::<T>::NonExistentCollection)?;
+			let owner = Self::owner_of(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+
+			ensure!(sender == collection.curator || sender == owner, Error::<T>::NotCuratorOrOwner);
+			ensure!(
+				!<LetterCollections<T>>::contains_key(letter_id, collection_id),
+				Error::<T>::AlreadyInCollection
+			);
+
+			// Both the curator and the letter owner must agree on the entry. Consent given
+			// by a previous owner of the letter is not honoured.
+			let consented = match Self::pending_entry(collection_id, letter_id) {
+				Some(who) => who != sender && (who == collection.curator || who == owner),
+				None => sender == collection.curator && sender == owner,
+			};
+
+			if !consented {
+				Self::propose_entry(collection_id, letter_id, &sender)?;
+				Self::deposit_event(Event::CollectionEntryProposed(
+					sender,
+					collection_id,
+					letter_id,
+				));
+				return Ok(().into())
+			}
+
+			// A pending entry already counts towards the letter's entries.
+			if !<PendingEntries<T>>::contains_key(collection_id, letter_id) {
+				ensure!(
+					Self::letter_entry_count(letter_id) < T::MaxLetterEntries::get(),
+					Error::<T>::TooManyLetterEntries
+				);
+			}
+
+			<CollectionEntries<T>>::try_mutate(collection_id, |entries| entries.try_push(letter_id))
+				.map_err(|_| Error::<T>::CollectionFull)?;
+			<LetterCollections<T>>::insert(letter_id, collection_id, ());
+			Self::withdraw_entry(collection_id, letter_id);
+			<LetterEntryCount<T>>::mutate(letter_id, |count| *count += 1);
+
+			Self::deposit_event(Event::AddedToCollection(sender, collection_id, letter_id));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_from_collection())]
+		pub fn remove_from_collection(
+			origin: OriginFor<T>,
+			collection_id: u64,
+			letter_id: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (collection, _) =
+				Self::collection(collection_id).ok_or(Error::<T>::NonExistentCollection)?;
+			let owner = Self::owner_of(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+
+			// Either party can withdraw the letter from the collection, or its pending entry.
+			ensure!(sender == collection.curator || sender == owner, Error::<T>::NotCuratorOrOwner);
+			if !Self::withdraw_entry(collection_id, letter_id) {
+				ensure!(
+					<LetterCollections<T>>::contains_key(letter_id, collection_id),
+					Error::<T>::NotInCollection
+				);
+
+				<CollectionEntries<T>>::mutate(collection_id, |entries| {
+					entries.retain(|id| *id != letter_id)
+				});
+				<LetterCollections<T>>::remove(letter_id, collection_id);
+				Self::release_letter_entry(letter_id);
+			}
+
+			Self::deposit_event(Event::RemovedFromCollection(sender, collection_id, letter_id));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::move_in_collection())]
+		pub fn move_in_collection(
+			origin: OriginFor<T>,
+			collection_id: u64,
+			letter_id: T::Hash,
+			position: u32,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (collection, _) =
+				Self::collection(collection_id).ok_or(Error::<T>::NonExistentCollection)?;
+			ensure!(sender == collection.curator, Error::<T>::NotCurator);
+
+			let mut entries = Self::collection_entries(collection_id).into_inner();
+			let index =
+				entries.iter().position(|id| *id == letter_id).ok_or(Error::<T>::NotInCollection)?;
+			ensure!((position as usize) < entries.len(), Error::<T>::InvalidPosition);
+
+			let entry = entries.remove(index);
+			entries.insert(position as usize, entry);
+
+			let bounded_entries: BoundedVec<T::Hash, T::MaxCollectionSize> =
+				entries.try_into().map_err(|()| Error::<T>::CollectionFull)?;
+			<CollectionEntries<T>>::insert(collection_id, bounded_entries);
+
+			Self::deposit_event(Event::CollectionEntryMoved(
+				sender,
+				collection_id,
+				letter_id,
+				position,
+			));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::destroy_collection(
+			T::MaxCollectionSize::get(),
+			T::MaxPendingEntries::get(),
+		))]
+		pub fn destroy_collection(
+			origin: OriginFor<T>,
+			collection_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (collection, reserve) =
+				Self::collection(collection_id).ok_or(Error::<T>::NonExistentCollection)?;
+			ensure!(sender == collection.curator, Error::<T>::NotCurator);
+
+			// The letters stay with their owners, only their entries are removed.
+			let entries = <CollectionEntries<T>>::take(collection_id);
+			for letter_id in entries.iter() {
+				<LetterCollections<T>>::remove(letter_id, collection_id);
+				Self::release_letter_entry(*letter_id);
+			}
+			let mut pending = 0u32;
+			for (letter_id, _) in <PendingEntries<T>>::drain_prefix(collection_id) {
+				<LetterPendingEntries<T>>::remove(letter_id, collection_id);
+				Self::release_letter_entry(letter_id);
+				pending += 1;
+			}
+			<PendingEntryCount<T>>::remove(collection_id);
+
+			<Collections<T>>::remove(collection_id);
+			T::Currency::unreserve(&sender, reserve);
+
+			Self::deposit_event(Event::CollectionDestroyed(sender, collection_id));
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::destroy_collection(
+				entries.len() as u32,
+				pending,
+			))
+			.into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_book(title.len() as u32))]
+		pub fn create_book(origin: OriginFor<T>, title: Vec<u8>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let bounded_title: BoundedVec<u8, T::MaxTitleLength> =
+				title.try_into().map_err(|()| Error::<T>::TitleLenOverflow)?;
+
+			let book_id =
+				Self::books_count().checked_add(1).ok_or(Error::<T>::BookCountOverflow)?;
+
+			// reserve book deposit
+			let reserve = <DepositParameters<T>>::get().letter_base +
+				Self::title_deposit(bounded_title.len());
+			T::Currency::reserve(&sender, reserve)?;
+
+			let book = Book {
+				id: book_id,
+				owner: sender.clone(),
+				title: bounded_title,
+				price: 0u8.into(),
+				chapters: Default::default(),
+			};
+
+			<Books<T>>::insert(book_id, (book, reserve));
+			<BooksCount<T>>::put(book_id);
+
+			Self::deposit_event(Event::BookCreated(sender, book_id));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_chapter(title.len() as u32))]
+		pub fn add_chapter(
+			origin: OriginFor<T>,
+			book_id: u64,
+			letter_id: T::Hash,
+			title: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (mut book, mut reserve) = Self::book(book_id).ok_or(Error::<T>::NonExistentBook)?;
+			ensure!(book.owner == sender, Error::<T>::BookNotOwned);
+
+			// The chapter must belong to the owner of the book.
+			let owner = Self::owner_of(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(owner == sender, Error::<T>::LetterNotOwned);
+			ensure!(!<ChapterOf<T>>::contains_key(letter_id), Error::<T>::AlreadyChapter);
+
+			// A chapter that cannot change hands would keep the whole book from being sold.
+			let (letter, _) = Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(
+				letter.transfer_policy == TransferPolicy::Transferable,
+				Error::<T>::LetterSoulbound
+			);
+			ensure!(!<Frozen<T>>::contains_key(letter_id), Error::<T>::LetterFrozen);
+			ensure!(!Self::is_hidden(letter_id), Error::<T>::LetterHidden);
+
+			let bounded_title: BoundedVec<u8, T::MaxTitleLength> =
+				title.try_into().map_err(|()| Error::<T>::TitleLenOverflow)?;
+
+			// reserve chapter title deposit, kept with the chapter to be released as it was paid
+			let deposit = Self::title_deposit(bounded_title.len());
+			book.chapters
+				.try_push(Chapter { letter_id, title: bounded_title, deposit })
+				.map_err(|_| Error::<T>::ChapterCountOverflow)?;
+			T::Currency::reserve(&sender, deposit)?;
+			reserve += deposit;
+
+			<Books<T>>::insert(book_id, (book, reserve));
+			<ChapterOf<T>>::insert(letter_id, book_id);
+
+			Self::deposit_event(Event::ChapterAdded(sender, book_id, letter_id));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::detach_chapter())]
+		pub fn detach_chapter(
+			origin: OriginFor<T>,
+			book_id: u64,
+			letter_id: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (mut book, mut reserve) = Self::book(book_id).ok_or(Error::<T>::NonExistentBook)?;
+			ensure!(book.owner == sender, Error::<T>::BookNotOwned);
+			ensure!(Self::chapter_of(letter_id) == Some(book_id), Error::<T>::NotChapter);
+
+			// release chapter title deposit
+			if let Some(index) = book.chapters.iter().position(|c| c.letter_id == letter_id) {
+				let chapter = book.chapters.remove(index);
+				T::Currency::unreserve(&sender, chapter.deposit);
+				reserve -= chapter.deposit;
+			}
+
+			<Books<T>>::insert(book_id, (book, reserve));
+			<ChapterOf<T>>::remove(letter_id);
+
+			Self::deposit_event(Event::ChapterDetached(sender, book_id, letter_id));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_book_price())]
+		pub fn set_book_price(
+			origin: OriginFor<T>,
+			book_id: u64,
+			new_price: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (mut book, reserve) = Self::book(book_id).ok_or(Error::<T>::NonExistentBook)?;
+			ensure!(book.owner == sender, Error::<T>::BookNotOwned);
+
+			book.price = new_price;
+			<Books<T>>::insert(book_id, (book, reserve));
+
+			Self::deposit_event(Event::BookPriceSet(sender, book_id, new_price));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_book(T::MaxChapters::get()))]
+		#[transactional]
+		pub fn transfer_book(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			book_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (book, _) = Self::book(book_id).ok_or(Error::<T>::NonExistentBook)?;
+			ensure!(book.owner == sender, Error::<T>::BookNotOwned);
+
+			let chapters = book.chapters.len() as u32;
+			Self::transfer_book_from(sender, to, book_id)?;
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::transfer_book(chapters)).into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::buy_book(T::MaxChapters::get()))]
+		#[transactional]
+		pub fn buy_book(
+			origin: OriginFor<T>,
+			book_id: u64,
+			ask_price: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (book, _) = Self::book(book_id).ok_or(Error::<T>::NonExistentBook)?;
+			let owner = book.owner.clone();
+			ensure!(owner != sender, Error::<T>::CannotBuyOwnBook);
+
+			let book_price = book.price;
+			ensure!(!book_price.is_zero(), Error::<T>::BookNotForSale);
+			ensure!(book_price <= ask_price, Error::<T>::BookOutOfBudget);
+			// a chapter hidden from sale keeps the whole book from being sold
+			for chapter in book.chapters.iter() {
+				ensure!(!Self::is_hidden(chapter.letter_id), Error::<T>::LetterHidden);
+			}
+
+			<pallet_balances::Pallet<T> as Currency<_>>::transfer(
+				&sender,
+				&owner,
+				book_price,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			Self::transfer_book_from(owner.clone(), sender.clone(), book_id)?;
+
+			// Set the price of the book to the new price it was sold at.
+			<Books<T>>::mutate(book_id, |book| {
+				if let Some((book, _)) = book {
+					book.price = ask_price;
+				}
+			});
+
+			Self::deposit_event(Event::BookBought(sender, owner, book_id, book_price));
+
+			let chapters = book.chapters.len() as u32;
+			Ok(Some(<T as pallet::Config>::WeightInfo::buy_book(chapters)).into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::destroy_book(T::MaxChapters::get()))]
+		pub fn destroy_book(origin: OriginFor<T>, book_id: u64) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (book, reserve) = Self::book(book_id).ok_or(Error::<T>::NonExistentBook)?;
+			ensure!(book.owner == sender, Error::<T>::BookNotOwned);
+
+			// The chapters stay with the owner as letters of their own, and the deposits of the
+			// book and of every chapter title are returned.
+			for chapter in book.chapters.iter() {
+				<ChapterOf<T>>::remove(chapter.letter_id);
+			}
+			<Books<T>>::remove(book_id);
+			T::Currency::unreserve(&sender, reserve);
+
+			Self::deposit_event(Event::BookDestroyed(sender, book_id));
+
+			let chapters = book.chapters.len() as u32;
+			Ok(Some(<T as pallet::Config>::WeightInfo::destroy_book(chapters)).into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::invite_contributor())]
+		pub fn invite_contributor(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			who: T::AccountId,
+			role: Role,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			Self::ensure_can_manage(letter_id, &sender, role)?;
+			ensure!(Self::owner_of(letter_id) != Some(who.clone()), Error::<T>::AlreadyContributor);
+			ensure!(
+				Self::contributor_role(letter_id, &who).is_none(),
+				Error::<T>::AlreadyContributor
+			);
+
+			<Contributors<T>>::try_mutate(letter_id, |contributors| {
+				contributors.try_push((who.clone(), role))
+			})
+			.map_err(|_| Error::<T>::ContributorCountOverflow)?;
+
+			Self::deposit_event(Event::ContributorInvited(sender, letter_id, who, role));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_contributor())]
+		pub fn revoke_contributor(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let role = Self::contributor_role(letter_id, &who).ok_or(Error::<T>::NotContributor)?;
+			Self::ensure_can_manage(letter_id, &sender, role)?;
+
+			// Pages already written stay on the letter, along with their deposit.
+			<Contributors<T>>::mutate(letter_id, |contributors| {
+				contributors.retain(|(account, _)| *account != who)
+			});
+
+			Self::deposit_event(Event::ContributorRevoked(sender, letter_id, who));
+
+			Ok(().into())
+		}
+	}
+
+	// Helper functions
+	impl<T: Config> Pallet<T> {
+		/// The id of the letter minted with sequence number `nonce`.
+		///
+		/// Every letter and print, including genesis letters, takes the current `Nonce` as its
+		/// sequence number, so ids never repeat. Clients can derive the id off-chain as
+		/// `T::Hashing` of `b"letterid"` followed by `nonce` as a little-endian `u64`.
+		pub fn letter_id_of(nonce: u64) -> T::Hash {
+			T::Hashing::hash_of(&(LETTER_ID_DOMAIN, nonce))
+		}
+
+		// Helper to derive the asset in which the shares of a Letter are issued, consuming a
+		// sequence number so every fractionalization gets a new asset, even within one block.
+		fn next_fraction_asset_id(letter_id: T::Hash) -> AssetIdOf<T> {
+			let nonce = <FractionNonce<T>>::mutate(|nonce| {
+				*nonce = nonce.wrapping_add(1);
+				*nonce
+			});
+			let entropy = T::Hashing::hash_of(&(FRACTION_ID_DOMAIN, letter_id, nonce));
+			AssetIdOf::<T>::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+				.expect("infinite length input; no invalid inputs for type; qed")
+		}
+
+		// Helper to take the next letter id, consuming its sequence number
+		fn next_letter_id() -> Result<T::Hash, DispatchError> {
+			<Nonce<T>>::try_mutate(|nonce| {
+				let letter_id = Self::letter_id_of(*nonce);
+				*nonce = nonce.checked_add(1).ok_or(Error::<T>::NonceOverflow)?;
+
+				Ok(letter_id)
+			})
+		}
+
+		// Helper to check whether `amount` is too small to be paid to `to`, who holds nothing
+		// of the currency yet and would need at least its minimum balance.
+		fn below_minimum(
+			asset: AssetKind<AssetIdOf<T>>,
+			to: &T::AccountId,
+			amount: T::Balance,
+		) -> bool {
+			match asset {
+				AssetKind::Native => {
+					let total = <pallet_balances::Pallet<T> as Currency<_>>::total_balance(to);
+					total.is_zero() &&
+						amount < <pallet_balances::Pallet<T> as Currency<_>>::minimum_balance()
+				},
+				// An amount the asset cannot hold is left for `pay` to reject.
+				AssetKind::Asset(asset_id) => match Self::asset_amount(amount) {
+					Ok(amount) =>
+						T::Assets::balance(asset_id, to).is_zero() &&
+							amount < T::Assets::minimum_balance(asset_id),
+					Err(_) => false,
+				},
+			}
+		}
+
+		// Helper to pay `amount` of the native currency or of an asset
+		fn pay(
+			asset: AssetKind<AssetIdOf<T>>,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			match asset {
+				AssetKind::Native => <pallet_balances::Pallet<T> as Currency<_>>::transfer(
+					from,
+					to,
+					amount,
+					ExistenceRequirement::KeepAlive,
+				),
+				AssetKind::Asset(asset_id) => {
+					let amount = Self::asset_amount(amount)?;
+					<T::Assets as Transfer<_>>::transfer(asset_id, from, to, amount, true)
+						.map(|_| ())
+				},
+			}
+		}
+
+		// Helper to convert a price into the balance type of the assets, failing rather than
+		// rounding a price the asset cannot represent.
+		fn asset_amount(amount: T::Balance) -> Result<AssetBalanceOf<T>, DispatchError> {
+			let amount: u128 =
+				amount.try_into().map_err(|_| Error::<T>::PriceConversionOverflow)?;
+			amount.try_into().map_err(|_| Error::<T>::PriceConversionOverflow.into())
+		}
+
+		/// The account holding fractionalized letters.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		// Helper to bring every deposit held for a Letter in line with the current deposit
+		// parameters. Returns the new reserve of the Letter and its number of pages.
+		fn recompute_deposits(letter_id: T::Hash) -> Result<(BalanceOf<T>, u32), DispatchError> {
+			use sp_std::collections::btree_map::BTreeMap;
+
+			let (letter, mut reserve) =
+				Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+
+			// Each page with bytes on chain is paid by whoever stored it. Pages stored off chain
+			// are paid by the contributor who wrote them, the others are part of the reserve.
+			// Redacted pages released their deposit already.
+			let mut target = deposit::letter::<T>(letter.title.len(), letter.author.len());
+			let mut contributors: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+			for (index, page) in letter.pages.iter().enumerate() {
+				let index = index as u32;
+				if let Some(page_hash) = page {
+					deposit::set_page::<T>(*page_hash, letter_id, index)?;
+					continue
+				}
+				if !<OffchainPages<T>>::contains_key(letter_id, index) {
+					continue
+				}
+
+				let page_deposit = deposit::offchain_page::<T>();
+				match Self::page_writer(letter_id, index) {
+					Some(writer) if <ContributorDeposits<T>>::contains_key(letter_id, &writer) => {
+						let held = contributors.entry(writer).or_default();
+						*held = held.saturating_add(page_deposit);
+					},
+					_ => target = target.saturating_add(page_deposit),
+				}
+			}
+
+			deposit::set::<T>(letter_id, &mut reserve, target)?;
+			for who in <ContributorDeposits<T>>::iter_key_prefix(letter_id).collect::<Vec<_>>() {
+				let held = contributors.get(&who).copied().unwrap_or_default();
+				deposit::set_contributor::<T>(letter_id, &who, held)?;
+			}
+
+			let pages = letter.pages.len() as u32;
+			<Letters<T>>::insert(letter_id, (letter, reserve));
+
+			Ok((reserve, pages))
+		}
+
+		// Helper to compute the rent owed for the pages of a Letter over a number of blocks.
+		// Bytes shared with other letters are rented by each of them.
+		fn rent_of(letter_id: T::Hash, blocks: T::BlockNumber) -> BalanceOf<T> {
+			let bytes = Self::letter_bytes(letter_id);
+			let blocks: u32 = blocks.saturated_into();
+			T::RentPerByte::get().saturating_mul(bytes.into()).saturating_mul(blocks.into())
+		}
+
+		// Helper to charge the rent a Letter owes since it was last paid, from the free balance
+		// of its deposit holder. Returns whether the rent is paid up to the current block.
+		fn charge_rent(letter_id: T::Hash) -> Result<bool, DispatchError> {
+			if T::RentPerByte::get().is_zero() {
+				return Ok(true)
+			}
+
+			// letters minted before rent was introduced start paying from their first collection
+			let now = <frame_system::Pallet<T>>::block_number();
+			let paid_until = match Self::rent_paid_until(letter_id) {
+				Some(paid_until) => paid_until,
+				None => {
+					<RentPaidUntil<T>>::insert(letter_id, now);
+					return Ok(true)
+				},
+			};
+			if paid_until >= now {
+				return Ok(true)
+			}
 
-			// Transfer ownership of letter
-			Self::transfer_from(owner.clone(), sender.clone(), letter_id).expect(
-				"`owner` is shown to own the letter; \
-    				`owner` must have greater than 0 letters, so transfer cannot cause underflow; \
-    				`all_letter_count` shares the same type as `owned_letter_count` \
-    				and minting ensure there won't ever be more than `max()` letter, \
-    				which means transfer cannot cause an overflow;",
-			);
+			let rent = Self::rent_of(letter_id, now - paid_until);
+			if !rent.is_zero() {
+				let holder =
+					Self::deposit_holder(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+				match T::Currency::withdraw(
+					&holder,
+					rent,
+					WithdrawReasons::FEE,
+					ExistenceRequirement::KeepAlive,
+				) {
+					Ok(imbalance) => T::Rent::on_unbalanced(imbalance),
+					Err(_) => return Ok(false),
+				}
+				Self::deposit_event(Event::RentCollected(letter_id, holder, rent));
+			}
+			<RentPaidUntil<T>>::insert(letter_id, now);
+
+			Ok(true)
+		}
+
+		// Helper to collect the rent of a Letter before it is changed, failing if it cannot be paid
+		fn ensure_rent_paid(letter_id: T::Hash) -> DispatchResult {
+			ensure!(Self::charge_rent(letter_id)?, Error::<T>::RentUnpaid);
+			Ok(())
+		}
+
+		// Helper to collect the rent of a Letter, archiving it when the rent has been unpaid for
+		// longer than `Config::RentGracePeriod`.
+		fn settle_rent(letter_id: T::Hash) -> DispatchResult {
+			if Self::charge_rent(letter_id)? {
+				return Ok(())
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let paid_until = Self::rent_paid_until(letter_id).unwrap_or(now);
+			if now.saturating_sub(paid_until) > T::RentGracePeriod::get() {
+				Self::archive_letter(letter_id)?;
+			}
+
+			Ok(())
+		}
 
-			// Set the price of the letter to the new price it was sold at.
-			letter.price = ask_price.into();
+		// Helper to drop the pages of a Letter, keeping only their hash. The deposit of the letter
+		// stays reserved, so the letter can be restored as it was by uploading the same pages.
+		// The bytes of its pages go along with their deposit unless other letters refer to them,
+		// and restoring the letter charges that deposit again.
+		pub(crate) fn archive_letter(letter_id: T::Hash) -> DispatchResult {
+			let (mut letter, reserve) =
+				Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+
+			let pages: Vec<_> = letter.pages.iter().map(Self::page_bytes).collect();
+			let content_hash = T::Hashing::hash_of(&pages);
+			for (index, page) in letter.pages.iter().enumerate() {
+				if let Some(page_hash) = page {
+					Self::unstore_page(letter_id, index as u32, *page_hash, DepositAction::Refund);
+				}
+			}
+			letter.pages = Default::default();
 			<Letters<T>>::insert(letter_id, (letter, reserve));
+			<Archived<T>>::insert(letter_id, content_hash);
+			// nothing is stored anymore, so no rent is owed until the letter is restored
+			<RentPaidUntil<T>>::insert(letter_id, <frame_system::Pallet<T>>::block_number());
 
-			Self::deposit_event(Event::Bought(sender, owner, letter_id, letter_price));
+			Self::deposit_event(Event::LetterArchived(letter_id, content_hash));
 
-			Ok(().into())
+			Ok(())
 		}
-	}
 
-	// Helper functions
-	impl<T: Config> Pallet<T> {
-		// Helper to increment nonce
-		fn increment_nonce() -> DispatchResult {
-			<Nonce<T>>::try_mutate(|nonce| {
-				let next = nonce.checked_add(1).ok_or(Error::<T>::NonceOverflow)?;
-				*nonce = next;
+		// Helper to settle the rent of as many Letters as `remaining_weight` allows, carrying on
+		// from where the previous block stopped. Returns the weight used.
+		fn settle_rents(remaining_weight: Weight) -> Weight {
+			let base = T::DbWeight::get().reads_writes(2, 1);
+			if base > remaining_weight {
+				return 0
+			}
 
-				Ok(().into())
-			})
+			let all_letters_count = Self::all_letters_count();
+			if T::RentPerByte::get().is_zero() || all_letters_count == 0 {
+				return 0
+			}
+
+			// every letter is accounted for as if it had the most pages a letter can have
+			let per_letter = <T as pallet::Config>::WeightInfo::collect_rent(T::MaxPageNum::get());
+			let mut used = base;
+			let mut cursor = <RentCursor<T>>::get();
+			let mut settled = 0;
+			while settled < all_letters_count && used.saturating_add(per_letter) <= remaining_weight
+			{
+				cursor = cursor % all_letters_count + 1;
+				// a failure leaves this letter for the next round
+				let _ = Self::settle_rent(Self::letter_by_index(cursor));
+				used = used.saturating_add(per_letter);
+				settled += 1;
+			}
+			<RentCursor<T>>::put(cursor);
+
+			used
 		}
 
-		// Helper to generate letter id
-		fn letter_id(
-			sender: &T::AccountId,
-			title: BoundedVec<u8, T::MaxTitleLength>,
-			author: BoundedVec<u8, T::MaxAuthorLength>,
-		) -> T::Hash {
-			let nonce = <Nonce<T>>::get();
+		// Helper to mint a letter of the genesis config along with its pages
+		fn mint_genesis_letter(
+			genesis: &GenesisLetter<T::AccountId, T::Balance, T::Hash>,
+		) -> Result<(), &'static str> {
+			let title: BoundedVec<u8, T::MaxTitleLength> =
+				genesis.title.clone().try_into().map_err(|()| "title too long")?;
+			let author: BoundedVec<u8, T::MaxAuthorLength> =
+				genesis.author.clone().try_into().map_err(|()| "author too long")?;
+			ensure!(genesis.pages.len() as u32 <= T::MaxPageNum::get(), "too many pages");
+			for page in &genesis.pages {
+				ensure!(page.len() as u32 <= T::MaxPageLength::get(), "page too long");
+			}
+
+			if let Some(id) = genesis.id {
+				ensure!(!<LetterOwner<T>>::contains_key(id), "duplicate id");
+			}
+			let letter_id = Self::next_letter_id().map_err(|_| "nonce overflow")?;
+			if let Some(id) = genesis.id {
+				ensure!(id == letter_id, "id does not match the id scheme");
+			}
+
+			let letter = Letter {
+				id: letter_id,
+				title,
+				author,
+				price: genesis.price,
+				price_asset: AssetKind::Native,
+				transfer_policy: TransferPolicy::Transferable,
+				sealed: genesis.sealed,
+				pages: Default::default(),
+			};
+			Self::mint_letter(genesis.owner.clone(), letter_id, letter)?;
+
+			for page in &genesis.pages {
+				Self::mint_page(genesis.owner.clone(), letter_id, page.clone())?;
+			}
 
-			T::Hashing::hash_of(&(title, author, &sender, nonce))
+			Ok(())
 		}
 
 		// Helper to mint Letter
@@ -408,20 +2579,18 @@ pub mod pallet {
 				.checked_add(1)
 				.ok_or("Overflow adding a new letter to total supply")?;
 
-			// reserve page deposit
-			let reserve = T::LetterDepositBase::get() +
-				T::LetterDepositFactor::get() * (new_letter.title.len() as u32).into() +
-				T::LetterDepositFactor::get() * (new_letter.author.len() as u32).into();
-			T::Currency::reserve(&to, reserve)?;
+			// reserve letter deposit
+			let reserve = deposit::letter::<T>(new_letter.title.len(), new_letter.author.len());
+			deposit::open::<T>(letter_id, &to, reserve)?;
 
 			// update storage with new letter
 			<Letters<T>>::insert(letter_id, (new_letter, reserve));
 			<LetterOwner<T>>::insert(letter_id, Some(&to));
+			<RentPaidUntil<T>>::insert(letter_id, <frame_system::Pallet<T>>::block_number());
 
 			// write letter counting information to storage
-			<OwnedLettersArray<T>>::insert((to.clone(), new_owned_letter_count), letter_id);
+			<OwnedLetters<T>>::insert(&to, letter_id, ());
 			<OwnedLettersCount<T>>::insert(&to, new_owned_letter_count);
-			<OwnedLettersIndex<T>>::insert(letter_id, new_owned_letter_count);
 			<AllLettersArray<T>>::insert(new_all_letters_count, letter_id);
 			<AllLettersCount<T>>::put(new_all_letters_count);
 			<AllLettersIndex<T>>::insert(letter_id, new_all_letters_count);
@@ -432,39 +2601,265 @@ pub mod pallet {
 			Ok(())
 		}
 
+		// Helper to mint several pages at once, reserving the deposit of the new bytes among them
+		fn mint_pages(
+			sender: T::AccountId,
+			letter_id: T::Hash,
+			pages: BoundedVec<BoundedVec<u8, T::MaxPageLength>, T::MaxPageNum>,
+		) -> DispatchResult {
+			let is_owner = Self::owner_of(letter_id) == Some(sender.clone());
+			if !is_owner && Self::contributor_role(letter_id, &sender).is_none() {
+				return Err(Error::<T>::LetterNotOwned.into())
+			}
+
+			let (mut letter, reserve) =
+				Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+
+			let first_index = letter.pages.len() as u32;
+			for page in pages {
+				let index = letter.pages.len() as u32;
+				let page_hash = Self::store_page(&sender, letter_id, index, page)?;
+				letter
+					.pages
+					.try_push(Some(page_hash))
+					.map_err(|_| Error::<T>::PageCountOverflow)?;
+			}
+
+			for page_index in first_index..letter.pages.len() as u32 {
+				<PageWriter<T>>::insert(letter_id, page_index, &sender);
+			}
+			<Letters<T>>::insert(letter_id, (letter, reserve));
+
+			Ok(())
+		}
+
+		// Helper to append bytes to the last page, spilling over into a new page once it is full.
+		// Returns the indices of the first and last pages written to.
+		fn append_page_bytes(
+			sender: T::AccountId,
+			letter_id: T::Hash,
+			mut bytes: Vec<u8>,
+		) -> Result<(u32, u32), DispatchError> {
+			let is_owner = Self::owner_of(letter_id) == Some(sender.clone());
+			if !is_owner && Self::contributor_role(letter_id, &sender).is_none() {
+				return Err(Error::<T>::LetterNotOwned.into())
+			}
+
+			let (mut letter, reserve) =
+				Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			let mut pages = letter.pages.into_inner();
+
+			// only a page written by the sender on chain, uncompressed and not redacted since, can
+			// be extended, and a full page is left as it is
+			let max_len = T::MaxPageLength::get() as usize;
+			let last_page = (pages.len() as u32).checked_sub(1).filter(|&index| {
+				Self::page_writer(letter_id, index) == Some(sender.clone()) &&
+					!<RedactedPages<T>>::contains_key(letter_id, index) &&
+					!<OffchainPages<T>>::contains_key(letter_id, index) &&
+					!<CompressedPages<T>>::contains_key(letter_id, index) &&
+					Self::page_bytes(&pages[index as usize]).len() < max_len
+			});
+
+			let first_index = match last_page {
+				Some(index) => {
+					let page = &mut pages[index as usize];
+					let mut extended = Self::page_bytes(page).into_inner();
+					let room = max_len.saturating_sub(extended.len());
+					let rest = bytes.split_off(room.min(bytes.len()));
+					extended.append(&mut bytes);
+
+					// the extended page is stored anew, charging the sender only for the added
+					// bytes, and the bytes it had are dropped unless other pages refer to them
+					let extended = extended.try_into().map_err(|_| Error::<T>::PageLenOverflow)?;
+					*page = Some(match page.take() {
+						Some(page_hash) => {
+							Self::replace_page(&sender, letter_id, index, page_hash, extended)?
+						},
+						None => Self::store_page(&sender, letter_id, index, extended)?,
+					});
+
+					bytes = rest;
+					index
+				},
+				None => pages.len() as u32,
+			};
+
+			let mut last_index = first_index;
+			if last_page.is_none() || !bytes.is_empty() {
+				let page = bytes.try_into().map_err(|_| Error::<T>::PageLenOverflow)?;
+				let index = pages.len() as u32;
+				pages.push(Some(Self::store_page(&sender, letter_id, index, page)?));
+				last_index = pages.len() as u32 - 1;
+			}
+			letter.pages = pages.try_into().map_err(|_| Error::<T>::PageCountOverflow)?;
+
+			if last_page != Some(last_index) {
+				<PageWriter<T>>::insert(letter_id, last_index, &sender);
+			}
+			<Letters<T>>::insert(letter_id, (letter, reserve));
+
+			Ok((first_index, last_index))
+		}
+
 		// Helper to mint page
 		fn mint_page(sender: T::AccountId, letter_id: T::Hash, page: Vec<u8>) -> DispatchResult {
 			// check letter exists
 			ensure!(<LetterOwner<T>>::contains_key(letter_id), "Letter non-existent");
 
-			// check sender owns the letter
-			let letter_owner = Self::owner_of(letter_id);
-			if letter_owner != Some(sender.clone()) {
+			// check sender owns the letter or was invited to write on it
+			let is_owner = Self::owner_of(letter_id) == Some(sender.clone());
+			if !is_owner && Self::contributor_role(letter_id, &sender).is_none() {
 				return Err(Error::<T>::LetterNotOwned.into())
 			}
 
-			let (mut letter, mut reserve) = match Self::letter(letter_id) {
+			let (mut letter, reserve) = match Self::letter(letter_id) {
 				Some((l, r)) => (l, r),
 				None => return Err(Error::<T>::NonExistentLetter.into()),
 			};
 
 			let bounded_page: BoundedVec<u8, T::MaxPageLength> =
 				page.try_into().map_err(|()| Error::<T>::PageLenOverflow)?;
-			match letter.pages.try_push(bounded_page.clone()) {
+			ensure!(
+				letter.pages.len() < T::MaxPageNum::get() as usize,
+				Error::<T>::PageCountOverflow
+			);
+
+			let page_index = letter.pages.len() as u32;
+			let page_hash = Self::store_page(&sender, letter_id, page_index, bounded_page)?;
+			match letter.pages.try_push(Some(page_hash)) {
 				Ok(_) => (),
 				Err(_) => return Err(Error::<T>::PageCountOverflow.into()),
 			};
 
-			T::Currency::unreserve(&sender, reserve);
-			reserve += T::PageDepositBase::get() +
-				T::PageDepositFactor::get() * (bounded_page.len() as u32).into();
-			T::Currency::reserve(&sender, reserve)?;
-
 			<Letters<T>>::insert(letter_id, (letter, reserve));
+			<PageWriter<T>>::insert(letter_id, page_index, &sender);
 
 			Ok(())
 		}
 
+		// Helper to add a page stored off chain, keeping a page without bytes in its place.
+		// Returns the index of the new page.
+		fn mint_offchain_page(
+			sender: T::AccountId,
+			letter_id: T::Hash,
+			content: ContentId,
+		) -> Result<u32, DispatchError> {
+			let is_owner = Self::owner_of(letter_id) == Some(sender.clone());
+			if !is_owner && Self::contributor_role(letter_id, &sender).is_none() {
+				return Err(Error::<T>::LetterNotOwned.into())
+			}
+
+			let (mut letter, mut reserve) =
+				Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			letter.pages.try_push(None).map_err(|_| Error::<T>::PageCountOverflow)?;
+			let page_index = letter.pages.len() as u32 - 1;
+
+			// only the content identifier is stored, whatever the length of the page
+			let deposit = deposit::offchain_page::<T>();
+			if is_owner {
+				deposit::hold::<T>(letter_id, &mut reserve, deposit)?;
+			} else {
+				deposit::hold_contributor::<T>(letter_id, &sender, deposit)?;
+			}
+
+			<Letters<T>>::insert(letter_id, (letter, reserve));
+			<PageWriter<T>>::insert(letter_id, page_index, &sender);
+			<OffchainPages<T>>::insert(letter_id, page_index, content);
+
+			Ok(page_index)
+		}
+
+		// Helper to store the bytes of the page at `index` of a Letter, or refer to the same bytes
+		// if they are stored already. Every page referring to the bytes reserves its own deposit
+		// from `who`. Returns the hash of the bytes.
+		fn store_page(
+			who: &T::AccountId,
+			letter_id: T::Hash,
+			index: u32,
+			bytes: BoundedVec<u8, T::MaxPageLength>,
+		) -> Result<T::Hash, DispatchError> {
+			let page_hash = T::Hashing::hash(&bytes[..]);
+			let deposit = deposit::page::<T>(bytes.len());
+			deposit::hold_page::<T>(page_hash, letter_id, index, who, deposit)?;
+			Self::add_page_bytes(letter_id, page_hash, bytes);
+
+			Ok(page_hash)
+		}
+
+		// Helper to drop the reference of the page at `index` of a Letter to stored bytes,
+		// releasing its deposit and removing the bytes once no page refers to them. Returns the
+		// amount of deposit released.
+		fn unstore_page(
+			letter_id: T::Hash,
+			index: u32,
+			page_hash: T::Hash,
+			action: DepositAction,
+		) -> BalanceOf<T> {
+			Self::remove_page_bytes(letter_id, page_hash);
+			deposit::release_page::<T>(page_hash, letter_id, index, action)
+		}
+
+		// Helper to point the page at `index` of a Letter from the bytes under `old_hash` to new
+		// bytes, moving only the difference of its deposit when `who` already holds it. Returns
+		// the hash of the new bytes.
+		fn replace_page(
+			who: &T::AccountId,
+			letter_id: T::Hash,
+			index: u32,
+			old_hash: T::Hash,
+			bytes: BoundedVec<u8, T::MaxPageLength>,
+		) -> Result<T::Hash, DispatchError> {
+			let page_hash = T::Hashing::hash(&bytes[..]);
+			let deposit = deposit::page::<T>(bytes.len());
+			deposit::move_page::<T>(old_hash, page_hash, letter_id, index, who, deposit)?;
+			Self::remove_page_bytes(letter_id, old_hash);
+			Self::add_page_bytes(letter_id, page_hash, bytes);
+
+			Ok(page_hash)
+		}
+
+		// Helper to add a reference to the bytes stored under `page_hash`, storing them if no page
+		// refers to them yet, and count them in the bytes of a Letter.
+		fn add_page_bytes(
+			letter_id: T::Hash,
+			page_hash: T::Hash,
+			bytes: BoundedVec<u8, T::MaxPageLength>,
+		) {
+			let len = bytes.len() as u32;
+			<PageStore<T>>::mutate(page_hash, |stored| match stored {
+				Some(page) => page.refs = page.refs.saturating_add(1),
+				None => *stored = Some(StoredPage { bytes, refs: 1 }),
+			});
+			<LetterBytes<T>>::mutate(letter_id, |bytes| *bytes = bytes.saturating_add(len));
+		}
+
+		// Helper to drop a reference to the bytes stored under `page_hash`, removing them once no
+		// page refers to them, and take them out of the bytes of a Letter.
+		fn remove_page_bytes(letter_id: T::Hash, page_hash: T::Hash) {
+			let len = Self::page_store(page_hash).map_or(0, |page| page.bytes.len() as u32);
+			match Self::letter_bytes(letter_id).saturating_sub(len) {
+				0 => <LetterBytes<T>>::remove(letter_id),
+				bytes => <LetterBytes<T>>::insert(letter_id, bytes),
+			}
+
+			<PageStore<T>>::mutate_exists(page_hash, |stored| match stored {
+				Some(page) if page.refs > 1 => page.refs -= 1,
+				_ => *stored = None,
+			});
+		}
+
+		// Helper to get the bytes of a page, which are empty for a page without bytes on chain
+		fn page_bytes(page: &Option<T::Hash>) -> BoundedVec<u8, T::MaxPageLength> {
+			page.and_then(Self::page_store).map(|stored| stored.bytes).unwrap_or_default()
+		}
+
+		// Helper to tell whether the page at `index` was left without bytes on chain, because it
+		// is stored off chain or was redacted.
+		fn has_no_bytes(letter_id: T::Hash, index: u32) -> bool {
+			<OffchainPages<T>>::contains_key(letter_id, index) ||
+				<RedactedPages<T>>::contains_key(letter_id, index)
+		}
+
 		// Helper to handle transferring a Letter from one account to another.
 		fn transfer_from(
 			from: T::AccountId,
@@ -474,14 +2869,54 @@ pub mod pallet {
 			// verify rightful owner
 			let owner = Self::owner_of(letter_id).ok_or("No owner for this letter")?;
 			ensure!(owner == from, "account does not own this letter");
+			// moving a letter to its owner would drop its contributors and miscount the owner
+			ensure!(from != to, Error::<T>::CannotTransferToSelf);
 
-			let (_, reserve) = match Self::letter(letter_id) {
-				Some((_, r)) => (.., r),
+			let (letter, reserve) = match Self::letter(letter_id) {
+				Some((l, r)) => (l, r),
 				None => return Err(Error::<T>::NonExistentLetter.into()),
 			};
 
-			T::Currency::unreserve(&from, reserve);
-			T::Currency::reserve(&to, reserve)?;
+			// Soulbound letters never change hands.
+			ensure!(
+				letter.transfer_policy == TransferPolicy::Transferable,
+				Error::<T>::LetterSoulbound
+			);
+			ensure!(!<Frozen<T>>::contains_key(letter_id), Error::<T>::LetterFrozen);
+
+			// whoever held the letter pays the rent it owes before it changes hands
+			Self::ensure_rent_paid(letter_id)?;
+
+			Self::move_letter(from, to, letter_id, reserve)
+		}
+
+		// Helper to move a Letter and its reserve to another account, regardless of its
+		// transfer policy.
+		fn move_letter(
+			from: T::AccountId,
+			to: T::AccountId,
+			letter_id: T::Hash,
+			reserve: BalanceOf<T>,
+		) -> DispatchResult {
+			// The reserve of a letter locked in the pallet account stays with whoever locked it.
+			if to != Self::account_id() {
+				deposit::transfer::<T>(letter_id, &to, reserve)?;
+
+				// Contributors are invited by an owner and leave with them. The new owner takes
+				// over the deposits of the pages they wrote, and they get theirs back.
+				<Contributors<T>>::remove(letter_id);
+				let held = <ContributorDeposits<T>>::iter_prefix_values(letter_id)
+					.fold(Zero::zero(), |total: BalanceOf<T>, held| total.saturating_add(held));
+				if !held.is_zero() {
+					let (letter, mut reserve) =
+						Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+					deposit::hold::<T>(letter_id, &mut reserve, held)?;
+					<Letters<T>>::insert(letter_id, (letter, reserve));
+					for (contributor, held) in <ContributorDeposits<T>>::drain_prefix(letter_id) {
+						T::Currency::unreserve(&contributor, held);
+					}
+				}
+			}
 
 			// count of letters owned by address to send from
 			let owned_letter_count_from = Self::owned_letter_count(&from);
@@ -499,22 +2934,10 @@ pub mod pallet {
 				.checked_add(1)
 				.ok_or("Transfer causes overflow of 'to' letter balance")?;
 
-			// get current letter index
-			let letter_index = <OwnedLettersIndex<T>>::get(letter_id);
-
-			// update storage items that require updated index.
-			if letter_index != new_owned_letter_count_from {
-				let last_letter_id =
-					<OwnedLettersArray<T>>::get((from.clone(), new_owned_letter_count_from));
-				<OwnedLettersArray<T>>::insert((from.clone(), letter_index), last_letter_id);
-				<OwnedLettersIndex<T>>::insert(last_letter_id, letter_index);
-			}
-
 			// write newletter ownership to storage items
 			<LetterOwner<T>>::insert(&letter_id, Some(&to));
-			<OwnedLettersIndex<T>>::insert(letter_id, owned_letter_count_to);
-			<OwnedLettersArray<T>>::remove((from.clone(), new_owned_letter_count_from));
-			<OwnedLettersArray<T>>::insert((to.clone(), new_owned_letter_count_to), letter_id);
+			<OwnedLetters<T>>::remove(&from, letter_id);
+			<OwnedLetters<T>>::insert(&to, letter_id, ());
 			<OwnedLettersCount<T>>::insert(&from, new_owned_letter_count_from);
 			<OwnedLettersCount<T>>::insert(&to, new_owned_letter_count_to);
 
@@ -523,10 +2946,284 @@ pub mod pallet {
 			Ok(())
 		}
 
+		// Helper to check the origin is either `Config::ForceOrigin` or the owner of a Letter.
+		fn ensure_owner_or_force(
+			origin: OriginFor<T>,
+			letter_id: T::Hash,
+		) -> Result<Freezer, DispatchError> {
+			let owner = Self::owner_of(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => Ok(Freezer::Force),
+				Err(origin) => {
+					let sender = ensure_signed(origin)?;
+					ensure!(owner == sender, Error::<T>::LetterNotOwned);
+					Ok(Freezer::Owner)
+				},
+			}
+		}
+
+		// Helper to remove a Letter from storage and release every deposit held for it.
+		// Returns the total amount of deposits released.
+		fn burn_from(
+			owner: T::AccountId,
+			letter_id: T::Hash,
+			action: DepositAction,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let (letter, reserve) = Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			let holder = Self::deposit_holder(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+
+			// count of letters owned by the owner
+			let owned_letter_count = Self::owned_letter_count(&owner);
+			let new_owned_letter_count = owned_letter_count
+				.checked_sub(1)
+				.ok_or("Burn causes underflow of owner letter balance")?;
+
+			<OwnedLetters<T>>::remove(&owner, letter_id);
+			<OwnedLettersCount<T>>::insert(&owner, new_owned_letter_count);
+
+			// move the last letter into the freed index of all letters.
+			let all_letters_count = Self::all_letters_count();
+			let all_index = <AllLettersIndex<T>>::take(letter_id);
+			if all_index != all_letters_count {
+				let last_letter_id = <AllLettersArray<T>>::get(all_letters_count);
+				<AllLettersArray<T>>::insert(all_index, last_letter_id);
+				<AllLettersIndex<T>>::insert(last_letter_id, all_index);
+			}
+			<AllLettersArray<T>>::remove(all_letters_count);
+			<AllLettersCount<T>>::put(all_letters_count.saturating_sub(1));
+
+			// release the deposits of the owner and of every contributor
+			let mut released = reserve;
+			deposit::release::<T>(&holder, reserve, action);
+			for (contributor, deposit) in <ContributorDeposits<T>>::drain_prefix(letter_id) {
+				deposit::release::<T>(&contributor, deposit, action);
+				released = released.saturating_add(deposit);
+			}
+			// bytes shared with other letters stay stored, along with the deposits of their pages
+			for (index, page) in letter.pages.iter().enumerate() {
+				if let Some(page_hash) = page {
+					let deposit = Self::unstore_page(letter_id, index as u32, *page_hash, action);
+					released = released.saturating_add(deposit);
+				}
+			}
+
+			for (collection_id, _) in <LetterCollections<T>>::drain_prefix(letter_id) {
+				<CollectionEntries<T>>::mutate(collection_id, |entries| {
+					entries.retain(|id| *id != letter_id)
+				});
+			}
+			let pending: Vec<u64> = <LetterPendingEntries<T>>::iter_key_prefix(letter_id).collect();
+			for collection_id in pending {
+				Self::withdraw_entry(collection_id, letter_id);
+			}
+			<LetterEntryCount<T>>::remove(letter_id);
+			<PageWriter<T>>::drain_prefix(letter_id).for_each(drop);
+			<RedactedPages<T>>::drain_prefix(letter_id).for_each(drop);
+			for (index, _) in <OffchainPages<T>>::drain_prefix(letter_id) {
+				sp_io::offchain_index::clear(&Self::indexed_page_key(letter_id, index));
+			}
+			<CompressedPages<T>>::drain_prefix(letter_id).for_each(drop);
+
+			// pending reports are moot once the letter is gone
+			for report in <Reports<T>>::take(letter_id) {
+				T::Currency::unreserve(&report.reporter, report.bond);
+			}
+			<Hidden<T>>::remove(letter_id);
+			<Contributors<T>>::remove(letter_id);
+			<RevenueSplits<T>>::remove(letter_id);
+			<Editions<T>>::remove(letter_id);
+			<EditionSupply<T>>::remove(letter_id);
+			// the print numbers already taken are not reused, only the count of prints drops
+			if let Some((master_id, _)) = <Prints<T>>::take(letter_id) {
+				match Self::live_prints(master_id).saturating_sub(1) {
+					0 => <LivePrints<T>>::remove(master_id),
+					live => <LivePrints<T>>::insert(master_id, live),
+				}
+			}
+			<Fractionalized<T>>::remove(letter_id);
+			<Frozen<T>>::remove(letter_id);
+			<RentPaidUntil<T>>::remove(letter_id);
+			<LetterBytes<T>>::remove(letter_id);
+			<Archived<T>>::remove(letter_id);
+			<DepositHolder<T>>::remove(letter_id);
+			<LetterOwner<T>>::remove(letter_id);
+			<Letters<T>>::remove(letter_id);
+
+			Ok(released)
+		}
+
+		// Helper to drop the bytes of a page and release its deposit, keeping its index so later
+		// pages do not move.
+		fn redact_page(
+			letter_id: T::Hash,
+			index: u32,
+			reason: T::Hash,
+			action: DepositAction,
+		) -> DispatchResult {
+			let (mut letter, mut reserve) =
+				Self::letter(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			ensure!(!<RedactedPages<T>>::contains_key(letter_id, index), Error::<T>::PageRedacted);
+
+			let page = letter.pages.get_mut(index as usize).ok_or(Error::<T>::NonExistentPage)?;
+			let page_hash = page.take();
+			<CompressedPages<T>>::remove(letter_id, index);
+
+			let deposit = match page_hash {
+				// The bytes only go once no other page refers to them.
+				Some(page_hash) => Self::unstore_page(letter_id, index, page_hash, action),
+				None if <OffchainPages<T>>::take(letter_id, index).is_some() => {
+					sp_io::offchain_index::clear(&Self::indexed_page_key(letter_id, index));
+
+					// A contributor holds the deposit of the pages they wrote, otherwise it is
+					// part of the Letter reserve.
+					let deposit = deposit::offchain_page::<T>();
+					let contributor = Self::page_writer(letter_id, index)
+						.filter(|writer| Self::contributor_deposit(letter_id, writer) >= deposit);
+					match contributor {
+						Some(writer) => {
+							<ContributorDeposits<T>>::mutate(letter_id, &writer, |held| {
+								*held -= deposit
+							});
+							deposit::release::<T>(&writer, deposit, action);
+						},
+						None =>
+							deposit::release_from::<T>(letter_id, &mut reserve, deposit, action),
+					}
+					deposit
+				},
+				None => Zero::zero(),
+			};
+
+			<Letters<T>>::insert(letter_id, (letter, reserve));
+			<RedactedPages<T>>::insert(letter_id, index, reason);
+
+			Self::deposit_event(Event::PageRedacted(letter_id, index, reason, deposit, action));
+
+			Ok(())
+		}
+
+		// Helper to get the pages and collection entries a burn of the Letter goes through.
+		// An archived letter no longer lists its pages, so it is charged for every page.
+		fn burn_weight_of(letter_id: T::Hash) -> (u32, u32) {
+			let pages = match Self::letter(letter_id) {
+				Some(_) if <Archived<T>>::contains_key(letter_id) => T::MaxPageNum::get(),
+				Some((letter, _)) => letter.pages.len() as u32,
+				None => 0,
+			};
+			(pages, Self::letter_entry_count(letter_id))
+		}
+
+		// Helper to record the consent of `who` to a collection entry, within `MaxPendingEntries`
+		// for the collection and `MaxLetterEntries` for the letter.
+		fn propose_entry(
+			collection_id: u64,
+			letter_id: T::Hash,
+			who: &T::AccountId,
+		) -> DispatchResult {
+			if !<PendingEntries<T>>::contains_key(collection_id, letter_id) {
+				let count = Self::pending_entry_count(collection_id);
+				ensure!(count < T::MaxPendingEntries::get(), Error::<T>::TooManyPendingEntries);
+				let entries = Self::letter_entry_count(letter_id);
+				ensure!(entries < T::MaxLetterEntries::get(), Error::<T>::TooManyLetterEntries);
+				<PendingEntryCount<T>>::insert(collection_id, count + 1);
+				<LetterPendingEntries<T>>::insert(letter_id, collection_id, ());
+				<LetterEntryCount<T>>::insert(letter_id, entries + 1);
+			}
+			<PendingEntries<T>>::insert(collection_id, letter_id, who);
+			Ok(())
+		}
+
+		// Helper to drop a pending collection entry. Returns whether there was one.
+		fn withdraw_entry(collection_id: u64, letter_id: T::Hash) -> bool {
+			if <PendingEntries<T>>::take(collection_id, letter_id).is_none() {
+				return false
+			}
+			<LetterPendingEntries<T>>::remove(letter_id, collection_id);
+			match Self::pending_entry_count(collection_id).saturating_sub(1) {
+				0 => <PendingEntryCount<T>>::remove(collection_id),
+				count => <PendingEntryCount<T>>::insert(collection_id, count),
+			}
+			Self::release_letter_entry(letter_id);
+			true
+		}
+
+		// Helper to drop one collection entry, pending or not, from the count of a letter.
+		fn release_letter_entry(letter_id: T::Hash) {
+			match Self::letter_entry_count(letter_id).saturating_sub(1) {
+				0 => <LetterEntryCount<T>>::remove(letter_id),
+				count => <LetterEntryCount<T>>::insert(letter_id, count),
+			}
+		}
+
+		// Helper to compute the deposit of a book title or chapter title
+		fn title_deposit(title_len: usize) -> BalanceOf<T> {
+			<DepositParameters<T>>::get().letter_factor * (title_len as u32).into()
+		}
+
+		// Helper to move a Book and every one of its chapters to another account.
+		fn transfer_book_from(
+			from: T::AccountId,
+			to: T::AccountId,
+			book_id: u64,
+		) -> DispatchResult {
+			let (mut book, reserve) = Self::book(book_id).ok_or(Error::<T>::NonExistentBook)?;
+			ensure!(book.owner == from, Error::<T>::BookNotOwned);
+			ensure!(from != to, Error::<T>::CannotTransferToSelf);
+
+			for chapter in book.chapters.iter() {
+				Self::transfer_from(from.clone(), to.clone(), chapter.letter_id)?;
+			}
+
+			T::Currency::unreserve(&from, reserve);
+			T::Currency::reserve(&to, reserve)?;
+
+			book.owner = to.clone();
+			<Books<T>>::insert(book_id, (book, reserve));
+
+			Self::deposit_event(Event::BookTransferred(from, to, book_id));
+
+			Ok(())
+		}
+
+		pub fn contributor_role(letter_id: T::Hash, who: &T::AccountId) -> Option<Role> {
+			Self::contributors(letter_id)
+				.iter()
+				.find(|(account, _)| account == who)
+				.map(|(_, role)| *role)
+		}
+
+		// Helper to check `who` may invite or revoke a contributor with `role`
+		fn ensure_can_manage(letter_id: T::Hash, who: &T::AccountId, role: Role) -> DispatchResult {
+			let owner = Self::owner_of(letter_id).ok_or(Error::<T>::NonExistentLetter)?;
+			if owner == *who {
+				return Ok(())
+			}
+
+			// editors may only manage writers
+			match (Self::contributor_role(letter_id, who), role) {
+				(Some(Role::Editor), Role::Writer) => Ok(()),
+				_ => Err(Error::<T>::CannotManageContributor.into()),
+			}
+		}
+
+		pub fn collections_of(letter_id: T::Hash) -> Vec<u64> {
+			<LetterCollections<T>>::iter_key_prefix(letter_id).collect()
+		}
+
+		pub fn letters_of(owner: &T::AccountId) -> Vec<T::Hash> {
+			<OwnedLetters<T>>::iter_key_prefix(owner).collect()
+		}
+
 		pub fn read_page(
 			letter_id: T::Hash,
 			page_index: usize,
-		) -> sp_std::result::Result<BoundedVec<u8, T::MaxPageLength>, DispatchError> {
+		) -> sp_std::result::Result<Page<BoundedVec<u8, T::MaxPageLength>>, DispatchError> {
+			// prints read their pages from the master letter
+			let letter_id = match Self::print_of(letter_id) {
+				Some((master_id, _)) => master_id,
+				None => letter_id,
+			};
+
 			let (letter, _) = match Self::letter(letter_id) {
 				Some((l, _)) => (l, ..),
 				None => return Err(Error::<T>::NonExistentLetter.into()),
@@ -537,8 +3234,277 @@ pub mod pallet {
 				return Err(Error::<T>::NonExistentPage.into())
 			}
 
-			let page = letter.pages[page_index].clone();
-			Ok(page)
+			if <RedactedPages<T>>::contains_key(letter_id, page_index as u32) {
+				return Ok(Page::Redacted)
+			}
+			if let Some(content) = Self::offchain_page(letter_id, page_index as u32) {
+				return Ok(Page::OffChain(content))
+			}
+
+			let page = Self::page_bytes(&letter.pages[page_index]);
+			match Self::compressed_page(letter_id, page_index as u32) {
+				Some(compression) => Ok(Page::OnChain(Self::decompress_page(compression, &page)?)),
+				None => Ok(Page::OnChain(page)),
+			}
+		}
+
+		/// Decompresses a page, failing with `InvalidCompression` rather than going past
+		/// `MaxPageLength`, so a small page can't be made to take up much more room once read.
+		pub fn decompress_page(
+			compression: Compression,
+			page: &[u8],
+		) -> sp_std::result::Result<BoundedVec<u8, T::MaxPageLength>, DispatchError> {
+			let mut decompressed = sp_std::vec![0; T::MaxPageLength::get() as usize];
+			let len = match compression {
+				Compression::Lz4 => lz4_flex::block::decompress_into(page, &mut decompressed)
+					.map_err(|_| Error::<T>::InvalidCompression)?,
+			};
+			decompressed.truncate(len);
+
+			decompressed.try_into().map_err(|_| Error::<T>::PageLenOverflow.into())
+		}
+
+		/// Checks `bytes` are the content of a page, comparing them with the bytes stored on chain
+		/// or with the content identifier of a page stored off chain. No bytes match a redacted
+		/// page.
+		pub fn verify_page(
+			letter_id: T::Hash,
+			page_index: usize,
+			bytes: &[u8],
+		) -> sp_std::result::Result<bool, DispatchError> {
+			Ok(match Self::read_page(letter_id, page_index)? {
+				Page::OnChain(page) => page[..] == *bytes,
+				Page::OffChain(content) =>
+					content.len as usize == bytes.len() &&
+						content.digest == sp_io::hashing::blake2_256(bytes),
+				Page::Redacted => false,
+			})
+		}
+
+		/// The key of a page written with `write_indexed_page` in the offchain database, which is
+		/// the SCALE encoding of `(*b"letters:page", letter_id, index)`. Clients read the page
+		/// with the `offchain_localStorageGet` RPC, from the `PERSISTENT` storage.
+		pub fn indexed_page_key(letter_id: T::Hash, index: u32) -> Vec<u8> {
+			(INDEXED_PAGE_PREFIX, letter_id, index).encode()
+		}
+
+		/// Reads a page written with `write_indexed_page` from the offchain database. It is only
+		/// there on nodes with offchain indexing enabled, and only readable from offchain workers.
+		pub fn indexed_page(letter_id: T::Hash, index: u32) -> Option<Vec<u8>> {
+			sp_io::offchain::local_storage_get(
+				StorageKind::PERSISTENT,
+				&Self::indexed_page_key(letter_id, index),
+			)
+		}
+
+		/// Checks that the letter indices agree with `Letters` and `LetterOwner`, and that every
+		/// deposit recorded by the pallet is actually reserved.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			use sp_std::collections::btree_map::BTreeMap;
+
+			let all_letters_count = Self::all_letters_count();
+			ensure!(
+				<Letters<T>>::iter_keys().count() as u64 == all_letters_count,
+				"AllLettersCount does not match the number of letters"
+			);
+			ensure!(
+				<LetterOwner<T>>::iter_keys().count() as u64 == all_letters_count,
+				"AllLettersCount does not match the number of letter owners"
+			);
+			ensure!(
+				<OwnedLetters<T>>::iter_keys().count() as u64 == all_letters_count,
+				"AllLettersCount does not match the number of owned letters"
+			);
+			for (owner, count) in <OwnedLettersCount<T>>::iter() {
+				ensure!(
+					<OwnedLetters<T>>::iter_key_prefix(&owner).count() as u64 == count,
+					"OwnedLettersCount does not match the letters of the owner"
+				);
+			}
+
+			for index in 1..=all_letters_count {
+				ensure!(<AllLettersArray<T>>::contains_key(index), "AllLettersArray has a gap");
+				let letter_id = Self::letter_by_index(index);
+				ensure!(
+					<Letters<T>>::contains_key(letter_id),
+					"AllLettersArray has an unknown letter"
+				);
+				ensure!(
+					<AllLettersIndex<T>>::get(letter_id) == index,
+					"AllLettersIndex does not point back to AllLettersArray"
+				);
+			}
+			ensure!(
+				!<AllLettersArray<T>>::contains_key(all_letters_count + 1),
+				"AllLettersArray has entries past AllLettersCount"
+			);
+
+			for (letter_id, (letter, _)) in <Letters<T>>::iter() {
+				ensure!(letter.id == letter_id, "Letter is stored under another id");
+				let owner = Self::owner_of(letter_id).ok_or("Letter has no owner")?;
+
+				ensure!(
+					<OwnedLetters<T>>::contains_key(&owner, letter_id),
+					"OwnedLetters does not agree with LetterOwner"
+				);
+				ensure!(
+					<DepositHolder<T>>::contains_key(letter_id),
+					"Letter has no deposit holder"
+				);
+				let bytes: u32 =
+					letter.pages.iter().map(|page| Self::page_bytes(page).len() as u32).sum();
+				ensure!(
+					Self::letter_bytes(letter_id) == bytes,
+					"LetterBytes does not match the pages of the letter"
+				);
+			}
+			ensure!(
+				<DepositHolder<T>>::iter_keys().count() as u64 == all_letters_count,
+				"DepositHolder has entries for unknown letters"
+			);
+			for letter_id in <LetterBytes<T>>::iter_keys() {
+				ensure!(<Letters<T>>::contains_key(letter_id), "LetterBytes of an unknown letter");
+			}
+			// archived letters keep the markers of their pages until they are restored
+			for (letter_id, index) in <OffchainPages<T>>::iter_keys() {
+				let (letter, _) =
+					Self::letter(letter_id).ok_or("Off-chain page of an unknown letter")?;
+				if <Archived<T>>::contains_key(letter_id) {
+					continue
+				}
+				let page =
+					letter.pages.get(index as usize).ok_or("Off-chain page does not exist")?;
+				ensure!(page.is_none(), "Off-chain page has bytes on chain");
+			}
+			for (letter_id, index, compression) in <CompressedPages<T>>::iter() {
+				let (letter, _) =
+					Self::letter(letter_id).ok_or("Compressed page of an unknown letter")?;
+				if <Archived<T>>::contains_key(letter_id) {
+					continue
+				}
+				let page =
+					letter.pages.get(index as usize).ok_or("Compressed page does not exist")?;
+				ensure!(
+					Self::decompress_page(compression, &Self::page_bytes(page)).is_ok(),
+					"Compressed page does not decompress"
+				);
+			}
+			let mut prints: BTreeMap<T::Hash, u32> = BTreeMap::new();
+			for (master_id, _) in <Prints<T>>::iter_values() {
+				*prints.entry(master_id).or_default() += 1;
+			}
+			ensure!(
+				<LivePrints<T>>::iter_keys().count() == prints.len(),
+				"LivePrints has masters without prints"
+			);
+			for (master_id, count) in prints {
+				ensure!(<Letters<T>>::contains_key(master_id), "Print of an unknown master");
+				ensure!(
+					Self::live_prints(master_id) == count,
+					"LivePrints does not match the prints of the master"
+				);
+			}
+			let mut pending = 0u32;
+			for (collection_id, letter_id) in <PendingEntries<T>>::iter_keys() {
+				ensure!(
+					<Collections<T>>::contains_key(collection_id),
+					"Pending entry of an unknown collection"
+				);
+				ensure!(
+					<Letters<T>>::contains_key(letter_id),
+					"Pending entry of an unknown letter"
+				);
+				ensure!(
+					<LetterPendingEntries<T>>::contains_key(letter_id, collection_id),
+					"LetterPendingEntries does not agree with PendingEntries"
+				);
+				pending += 1;
+			}
+			ensure!(
+				<LetterPendingEntries<T>>::iter_keys().count() as u32 == pending,
+				"LetterPendingEntries has entries that are not pending"
+			);
+			let mut counted = 0u32;
+			for (collection_id, count) in <PendingEntryCount<T>>::iter() {
+				ensure!(
+					<PendingEntries<T>>::iter_key_prefix(collection_id).count() as u32 == count,
+					"PendingEntryCount does not match the pending entries of the collection"
+				);
+				counted += count;
+			}
+			ensure!(counted == pending, "PendingEntryCount misses pending entries");
+			for (letter_id, count) in <LetterEntryCount<T>>::iter() {
+				let members = <LetterCollections<T>>::iter_key_prefix(letter_id).count();
+				let pending = <LetterPendingEntries<T>>::iter_key_prefix(letter_id).count();
+				ensure!(
+					(members + pending) as u32 == count,
+					"LetterEntryCount does not match the collection entries of the letter"
+				);
+			}
+			let entries = <LetterCollections<T>>::iter_keys().count() as u32 + pending;
+			let counted: u32 = <LetterEntryCount<T>>::iter_values().sum();
+			ensure!(counted == entries, "LetterEntryCount misses collection entries");
+
+			for (book_id, (book, reserve)) in <Books<T>>::iter() {
+				let mut deposits: BalanceOf<T> = Zero::zero();
+				for chapter in book.chapters.iter() {
+					ensure!(
+						Self::chapter_of(chapter.letter_id) == Some(book_id),
+						"ChapterOf does not agree with the chapters of the book"
+					);
+					deposits = deposits.saturating_add(chapter.deposit);
+				}
+				ensure!(deposits <= reserve, "Book reserve does not cover its chapter deposits");
+			}
+
+			for letter_id in <Archived<T>>::iter_keys() {
+				let (letter, _) =
+					Self::letter(letter_id).ok_or("Archived letter does not exist")?;
+				ensure!(letter.pages.is_empty(), "Archived letter still has pages");
+			}
+
+			let mut refs: BTreeMap<T::Hash, u32> = BTreeMap::new();
+			for (_, (letter, _)) in <Letters<T>>::iter() {
+				for page_hash in letter.pages.iter().flatten() {
+					*refs.entry(*page_hash).or_default() += 1;
+				}
+			}
+			ensure!(
+				<PageStore<T>>::iter_keys().count() == refs.len(),
+				"PageStore has bytes no page refers to"
+			);
+			for (page_hash, count) in refs {
+				let stored = Self::page_store(page_hash).ok_or("Page refers to no stored bytes")?;
+				ensure!(stored.refs == count, "Stored page has the wrong number of references");
+				ensure!(
+					<PageDeposits<T>>::iter_key_prefix(page_hash).count() as u32 == count,
+					"Stored page does not have a deposit for each reference"
+				);
+				ensure!(
+					T::Hashing::hash(&stored.bytes[..]) == page_hash,
+					"Stored page is not under the hash of its bytes"
+				);
+			}
+
+			for (page_hash, (letter_id, index)) in <PageDeposits<T>>::iter_keys() {
+				let (letter, _) =
+					Self::letter(letter_id).ok_or("Page deposit of an unknown letter")?;
+				ensure!(
+					letter.pages.get(index as usize) == Some(&Some(page_hash)),
+					"Page deposit of a page that does not refer to the bytes"
+				);
+			}
+
+			// Every reserve of an account the pallet holds deposits from is one of these deposits.
+			for (who, amount) in deposit::held::<T>() {
+				ensure!(
+					amount == T::Currency::reserved_balance(&who),
+					"Deposits held by the pallet do not match the reserved balance"
+				);
+			}
+
+			Ok(())
 		}
 	}
 }
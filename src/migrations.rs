@@ -0,0 +1,304 @@
+//! Storage migrations for pallet-letters
+
+use super::*;
+use frame_support::{
+	migration::remove_storage_prefix,
+	traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// Replaces the `OwnedLettersArray` and `OwnedLettersIndex` maps with `OwnedLetters`.
+	///
+	/// The new map and the owned counts are rebuilt from `LetterOwner`, so a corrupted
+	/// array index is not carried over.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let _ = <OwnedLettersCount<T>>::remove_all(None);
+		let mut letters: Weight = 0;
+		for (letter_id, owner) in <LetterOwner<T>>::iter() {
+			if let Some(owner) = owner {
+				<OwnedLetters<T>>::insert(&owner, letter_id, ());
+				<OwnedLettersCount<T>>::mutate(&owner, |count| *count += 1);
+			}
+			letters += 1;
+		}
+
+		let pallet = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+		remove_storage_prefix(pallet, b"OwnedLettersArray", &[]);
+		remove_storage_prefix(pallet, b"OwnedLettersIndex", &[]);
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(2 * letters + 1, 2 * letters + 4)
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Records the holder of the reserve of every letter in `DepositHolder`.
+	///
+	/// The reserve was held by the owner of the letter, or by whoever fractionalized it while it
+	/// is locked in the pallet account.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 2 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut letters: Weight = 0;
+		for (letter_id, owner) in <LetterOwner<T>>::iter() {
+			let holder = match <Fractionalized<T>>::get(letter_id) {
+				Some(fraction) => Some(fraction.depositor),
+				None => owner,
+			};
+			if let Some(holder) = holder {
+				<DepositHolder<T>>::insert(letter_id, holder);
+			}
+			letters += 1;
+		}
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(2 * letters + 1, letters + 1)
+	}
+}
+
+pub mod v3 {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::traits::{Hash, Saturating, Zero},
+	};
+	use scale_info::prelude::vec::Vec;
+
+	/// A letter as stored up to version 2, with the bytes of its pages.
+	#[derive(Encode, Decode)]
+	pub struct OldLetter<T: Config> {
+		pub id: T::Hash,
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		pub author: BoundedVec<u8, T::MaxAuthorLength>,
+		pub price: T::Balance,
+		pub pages: BoundedVec<BoundedVec<u8, T::MaxPageLength>, T::MaxPageNum>,
+	}
+
+	/// Moves the bytes of every page into `PageStore`, leaving their hash in the letter.
+	///
+	/// Letters stored before version 3 had no price asset, transfer policy nor seal, so they
+	/// are priced in the native currency, transferable and unsealed.
+	///
+	/// The deposit of a page, at the current deposit parameters, moves from the reserve of the
+	/// letter or from the contributor who wrote it to `PageDeposits`, still held by whoever paid
+	/// it. Pages sharing the same bytes keep paying for themselves.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 3 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut letters: Weight = 0;
+		let mut pages: Weight = 0;
+		<Letters<T>>::translate::<(OldLetter<T>, BalanceOf<T>), _>(|letter_id, (old, reserve)| {
+			let holder = <DepositHolder<T>>::get(letter_id);
+			let mut reserve = reserve;
+			let mut page_hashes = Vec::with_capacity(old.pages.len());
+			for (index, bytes) in old.pages.into_iter().enumerate() {
+				let index = index as u32;
+				pages += 1;
+				if bytes.is_empty() &&
+					(<OffchainPages<T>>::contains_key(letter_id, index) ||
+						<RedactedPages<T>>::contains_key(letter_id, index))
+				{
+					page_hashes.push(None);
+					continue
+				}
+
+				// A contributor held the deposit of the pages they wrote, otherwise it was
+				// part of the reserve.
+				let deposit = deposit::page::<T>(bytes.len());
+				let writer = <PageWriter<T>>::get(letter_id, index)
+					.filter(|writer| <ContributorDeposits<T>>::get(letter_id, writer) >= deposit);
+				let (payer, deposit) = match writer {
+					Some(writer) => {
+						<ContributorDeposits<T>>::mutate(letter_id, &writer, |held| {
+							*held = held.saturating_sub(deposit)
+						});
+						(writer, deposit)
+					},
+					None => match &holder {
+						Some(holder) => {
+							let deposit = deposit.min(reserve);
+							reserve = reserve.saturating_sub(deposit);
+							(holder.clone(), deposit)
+						},
+						// every letter has a deposit holder since version 2
+						None => (Pallet::<T>::account_id(), Zero::zero()),
+					},
+				};
+
+				let page_hash = T::Hashing::hash(&bytes[..]);
+				<PageDeposits<T>>::insert(page_hash, (letter_id, index), (payer, deposit));
+				<PageStore<T>>::mutate(page_hash, |stored| match stored {
+					Some(page) => page.refs = page.refs.saturating_add(1),
+					None => *stored = Some(StoredPage { bytes, refs: 1 }),
+				});
+				page_hashes.push(Some(page_hash));
+			}
+			letters += 1;
+
+			let letter = Letter {
+				id: old.id,
+				title: old.title,
+				author: old.author,
+				price: old.price,
+				price_asset: AssetKind::Native,
+				transfer_policy: TransferPolicy::Transferable,
+				sealed: false,
+				pages: page_hashes.try_into().ok()?,
+			};
+			Some((letter, reserve))
+		});
+
+		StorageVersion::new(3).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(2 * letters + 5 * pages + 1, letters + 3 * pages + 1)
+	}
+}
+
+pub mod v4 {
+	use super::*;
+	use sp_std::collections::btree_map::BTreeMap;
+
+	/// Counts the prints of every master in `LivePrints`, and the pending entries of every
+	/// collection in `PendingEntryCount`, indexing them by letter in `LetterPendingEntries`.
+	///
+	/// `EditionSupply` kept counting the prints that were burned, so the count is rebuilt from
+	/// the prints still in `Prints`. Collections may be left with more pending entries than
+	/// `MaxPendingEntries`, in which case they take no new ones until enough are withdrawn.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 4 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut prints: Weight = 0;
+		let mut live: BTreeMap<T::Hash, u32> = BTreeMap::new();
+		for (master_id, _) in <Prints<T>>::iter_values() {
+			*live.entry(master_id).or_default() += 1;
+			prints += 1;
+		}
+		let masters = live.len() as Weight;
+		for (master_id, count) in live {
+			<LivePrints<T>>::insert(master_id, count);
+		}
+
+		let mut pending: Weight = 0;
+		let mut counts: BTreeMap<u64, u32> = BTreeMap::new();
+		for (collection_id, letter_id) in <PendingEntries<T>>::iter_keys() {
+			<LetterPendingEntries<T>>::insert(letter_id, collection_id, ());
+			*counts.entry(collection_id).or_default() += 1;
+			pending += 1;
+		}
+		let collections = counts.len() as Weight;
+		for (collection_id, count) in counts {
+			<PendingEntryCount<T>>::insert(collection_id, count);
+		}
+
+		StorageVersion::new(4).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(prints + pending + 1, masters + pending + collections + 1)
+	}
+}
+
+pub mod v5 {
+	use super::*;
+	use frame_support::{pallet_prelude::*, sp_runtime::traits::Saturating};
+	use scale_info::prelude::vec::Vec;
+
+	/// A chapter as stored up to version 4, without the deposit paid for its title.
+	#[derive(Encode, Decode)]
+	pub struct OldChapter<T: Config> {
+		pub letter_id: T::Hash,
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+	}
+
+	/// A book as stored up to version 4.
+	#[derive(Encode, Decode)]
+	pub struct OldBook<T: Config> {
+		pub id: u64,
+		pub owner: T::AccountId,
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		pub price: T::Balance,
+		pub chapters: BoundedVec<OldChapter<T>, T::MaxChapters>,
+	}
+
+	/// Records the deposit paid for the title of every chapter, counts the collection entries of
+	/// every letter and the bytes it keeps on chain.
+	///
+	/// The deposit is computed at the current deposit parameters, and never goes beyond what is
+	/// left of the reserve of the book, so releasing every chapter cannot take more than it holds.
+	/// Letters already in more than `MaxLetterEntries` collections keep their entries, they only
+	/// cannot be proposed to another collection until enough of them are removed.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 5 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let factor = <DepositParameters<T>>::get().letter_factor;
+		let mut books: Weight = 0;
+		<Books<T>>::translate::<(OldBook<T>, BalanceOf<T>), _>(|_, (old, reserve)| {
+			books += 1;
+			let mut left = reserve;
+			let mut chapters = Vec::with_capacity(old.chapters.len());
+			for chapter in old.chapters {
+				let deposit = (factor * (chapter.title.len() as u32).into()).min(left);
+				left = left.saturating_sub(deposit);
+				chapters.push(Chapter {
+					letter_id: chapter.letter_id,
+					title: chapter.title,
+					deposit,
+				});
+			}
+
+			let book = Book {
+				id: old.id,
+				owner: old.owner,
+				title: old.title,
+				price: old.price,
+				chapters: chapters.try_into().ok()?,
+			};
+			Some((book, reserve))
+		});
+
+		let mut entries: Weight = 0;
+		let members = <LetterCollections<T>>::iter_keys();
+		let pending = <LetterPendingEntries<T>>::iter_keys();
+		for (letter_id, _) in members.chain(pending).collect::<Vec<_>>() {
+			entries += 1;
+			<LetterEntryCount<T>>::mutate(letter_id, |count| *count += 1);
+		}
+
+		let mut letters: Weight = 0;
+		let mut pages: Weight = 0;
+		for (letter_id, (letter, _)) in <Letters<T>>::iter() {
+			letters += 1;
+			let mut bytes = 0u32;
+			for page_hash in letter.pages.iter().flatten() {
+				pages += 1;
+				let stored = <PageStore<T>>::get(page_hash);
+				bytes += stored.map_or(0, |page| page.bytes.len() as u32);
+			}
+			if bytes > 0 {
+				<LetterBytes<T>>::insert(letter_id, bytes);
+			}
+		}
+
+		StorageVersion::new(5).put::<Pallet<T>>();
+
+		T::DbWeight::get()
+			.reads_writes(books + 2 * entries + letters + pages + 2, books + entries + letters + 1)
+	}
+}
@@ -1,10 +1,16 @@
 use crate as pallet_letters;
-use frame_support::{parameter_types, traits::Randomness};
+use crate::GenesisLetter;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU128, ConstU32, Randomness},
+	PalletId,
+};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -14,6 +20,7 @@ pub const LETTER_DEPOSIT_BASE: u32 = 50;
 pub const LETTER_DEPOSIT_FACTOR: u32 = 5;
 pub const PAGE_DEPOSIT_BASE: u32 = 10;
 pub const PAGE_DEPOSIT_FACTOR: u32 = 1;
+pub const REPORT_BOND: u32 = 20;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -24,7 +31,8 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-		Letters: pallet_letters::{Pallet, Call, Storage, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		Letters: pallet_letters::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
 
@@ -57,7 +65,7 @@ impl system::Config for Test {
 	type SystemWeightInfo = ();
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = ();
-	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxConsumers = ConstU32<16>;
 }
 
 parameter_types! {
@@ -65,10 +73,22 @@ parameter_types! {
 	pub const MaxAuthorLength: u32 = 64;
 	pub const MaxPageLength: u32 = 8192;
 	pub const MaxPageNum: u32 = 64;
+	pub const MaxDescriptionLength: u32 = 256;
+	pub const MaxCollectionSize: u32 = 16;
+	pub const MaxPendingEntries: u32 = 4;
+	pub const MaxLetterEntries: u32 = 4;
+	pub const MaxChapters: u32 = 16;
+	pub const MaxContributors: u32 = 8;
+	pub const MaxReports: u32 = 4;
+	pub const HideThreshold: u32 = 2;
+	pub const LettersPalletId: PalletId = PalletId(*b"py/lettr");
 	pub const LetterDepositBase: u32 = LETTER_DEPOSIT_BASE;
 	pub const LetterDepositFactor: u32 = LETTER_DEPOSIT_FACTOR;
 	pub const PageDepositBase: u32 = PAGE_DEPOSIT_BASE;
 	pub const PageDepositFactor: u32 = PAGE_DEPOSIT_FACTOR;
+	pub const ReportBond: u32 = REPORT_BOND;
+	pub const RentGracePeriod: u64 = 10;
+	pub static RentPerByte: u128 = 0;
 }
 
 impl pallet_letters::Config for Test {
@@ -78,11 +98,29 @@ impl pallet_letters::Config for Test {
 	type MaxPageLength = MaxPageLength;
 	type MaxPageNum = MaxPageNum;
 	type MaxTitleLength = MaxTitleLength;
+	type MaxDescriptionLength = MaxDescriptionLength;
+	type MaxCollectionSize = MaxCollectionSize;
+	type MaxPendingEntries = MaxPendingEntries;
+	type MaxLetterEntries = MaxLetterEntries;
+	type MaxChapters = MaxChapters;
+	type MaxContributors = MaxContributors;
+	type MaxReports = MaxReports;
+	type HideThreshold = HideThreshold;
 	type WeightInfo = ();
 	type LetterDepositBase = LetterDepositBase;
 	type LetterDepositFactor = LetterDepositFactor;
 	type PageDepositBase = PageDepositBase;
 	type PageDepositFactor = PageDepositFactor;
+	type ReportBond = ReportBond;
+	type Assets = Assets;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type ModerationOrigin = frame_system::EnsureRoot<u64>;
+	type ParameterOrigin = frame_system::EnsureRoot<u64>;
+	type Slash = ();
+	type Rent = ();
+	type RentPerByte = RentPerByte;
+	type RentGracePeriod = RentGracePeriod;
+	type PalletId = LettersPalletId;
 }
 
 parameter_types! {
@@ -116,3 +154,44 @@ impl pallet_balances::Config for Test {
 	type AccountStore = System;
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Test>;
 }
+
+impl pallet_assets::Config for Test {
+	type Event = Event;
+	// narrower than the native balance, so that some prices cannot be paid in assets
+	type Balance = u64;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type AssetDeposit = ConstU128<0>;
+	type MetadataDepositBase = ConstU128<0>;
+	type MetadataDepositPerByte = ConstU128<0>;
+	type ApprovalDeposit = ConstU128<0>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+}
+
+pub const ALICE_INIT_BALANCE: u128 = 1000;
+pub const BOB_INIT_BALANCE: u128 = 2000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	genesis_test_ext(vec![])
+}
+
+pub fn genesis_test_ext(
+	letters: Vec<GenesisLetter<u64, u128, <Test as frame_system::Config>::Hash>>,
+) -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities = GenesisConfig {
+		system: frame_system::GenesisConfig::default(),
+		balances: pallet_balances::GenesisConfig {
+			balances: vec![(1, ALICE_INIT_BALANCE), (2, BOB_INIT_BALANCE)],
+		},
+		letters: LettersConfig { letters },
+	}
+	.build_storage()
+	.unwrap()
+	.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
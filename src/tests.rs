@@ -1,35 +1,49 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
-use sp_runtime::BuildStorage;
-
-const ALICE_INIT_BALANCE: u128 = 1000;
-const BOB_INIT_BALANCE: u128 = 2000;
-
-pub fn new_test_ext() -> sp_io::TestExternalities {
-	let mut ext: sp_io::TestExternalities = GenesisConfig {
-		system: frame_system::GenesisConfig::default(),
-		balances: pallet_balances::GenesisConfig {
-			balances: vec![(1, ALICE_INIT_BALANCE), (2, BOB_INIT_BALANCE)],
-		},
-	}
-	.build_storage()
-	.unwrap()
-	.into();
-	ext.execute_with(|| System::set_block_number(1));
-	ext
+use crate::{
+	mock::*, AssetKind, Compression, ContentId, DepositAction, DepositParams, Error, Freezer,
+	GenesisLetter, Page, ReportReason, Role, TransferPolicy,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	migration::{have_storage_value, put_storage_value},
+	traits::{Currency, GetStorageVersion, Hooks, ReservableCurrency, StorageVersion},
+	BoundedVec, StorageHasher, Twox64Concat,
+};
+use proptest::prelude::*;
+use sp_core::offchain::{testing::TestOffchainExt, OffchainDbExt, OffchainWorkerExt};
+use sp_runtime::{
+	traits::{BadOrigin, BlakeTwo256, Hash},
+	Perbill,
+};
+
+// Runs a test and checks the storage invariants of the pallet once it is done.
+pub fn build_and_execute(test: impl FnOnce()) {
+	new_test_ext().execute_with(|| {
+		test();
+		Letters::do_try_state().unwrap();
+	});
+}
+
+fn on_chain(page: &[u8]) -> Page<BoundedVec<u8, MaxPageLength>> {
+	Page::OnChain(page.to_vec().try_into().unwrap())
 }
 
 // -------------------------------------------
 // works
 #[test]
 fn init_letter_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_eq!(Letters::all_letters_count(), 0);
 
 		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
 		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
 
-		assert_ok!(Letters::init_letter(Origin::signed(1), title.clone(), author.clone()));
+		assert_ok!(Letters::init_letter(
+			Origin::signed(1),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
 
 		assert_eq!(Letters::all_letters_count(), 1);
 
@@ -49,7 +63,12 @@ fn init_letter_works() {
 		let title2 = "hello world".as_bytes().to_vec();
 		let author2 = "bear".as_bytes().to_vec();
 
-		assert_ok!(Letters::init_letter(Origin::signed(1), title2.clone(), author2.clone()));
+		assert_ok!(Letters::init_letter(
+			Origin::signed(1),
+			title2.clone(),
+			author2.clone(),
+			TransferPolicy::Transferable
+		));
 
 		assert_eq!(Letters::all_letters_count(), 2);
 
@@ -73,27 +92,32 @@ fn init_letter_works() {
 
 #[test]
 fn read_write_page_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
 		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
 
-		assert_ok!(Letters::init_letter(Origin::signed(1), title.clone(), author.clone()));
+		assert_ok!(Letters::init_letter(
+			Origin::signed(1),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
 
 		let letter_id = Letters::letter_by_index(1);
 		let page = "𝔯𝔬𝔰𝔢𝔰 𝔞𝔯𝔢 𝔯𝔢𝔡 🌹".as_bytes().to_vec();
 
 		assert_ok!(Letters::write_page(Origin::signed(1), letter_id, page.clone()));
-		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), page);
+		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), on_chain(&page));
 
 		let page = "𝔳𝔦𝔬𝔩𝔢𝔱𝔰 𝔞𝔯𝔢 𝔟𝔩𝔲𝔢 ❃".as_bytes().to_vec();
 		assert_ok!(Letters::write_page(Origin::signed(1), letter_id, page.clone()));
-		assert_eq!(Letters::read_page(letter_id, 1).unwrap(), page);
+		assert_eq!(Letters::read_page(letter_id, 1).unwrap(), on_chain(&page));
 	});
 }
 
 #[test]
 fn transfer_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
 		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
 
@@ -102,7 +126,12 @@ fn transfer_works() {
 		let alice_signed = Origin::signed(alice);
 		let bob_signed = Origin::signed(bob);
 
-		assert_ok!(Letters::init_letter(alice_signed.clone(), title.clone(), author.clone()));
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
 
 		let letter_id = Letters::letter_by_index(1);
 		let page = "𝔯𝔬𝔰𝔢𝔰 𝔞𝔯𝔢 𝔯𝔢𝔡 🌹".as_bytes().to_vec();
@@ -117,7 +146,7 @@ fn transfer_works() {
 				PAGE_DEPOSIT_BASE as u128 +
 				(page.len() as u32 * PAGE_DEPOSIT_FACTOR) as u128
 		);
-		assert_eq!(Letters::letter_of_owner_by_index((alice, 1)), letter_id);
+		assert_eq!(Letters::letters_of(&alice), vec![letter_id]);
 		assert_eq!(Letters::owner_of(letter_id), Some(alice));
 		assert_eq!(Letters::owned_letter_count(alice), 1);
 		assert_eq!(Letters::owned_letter_count(bob), 0);
@@ -133,14 +162,14 @@ fn transfer_works() {
 				(page.len() as u32 * PAGE_DEPOSIT_FACTOR) as u128
 		);
 
-		assert_eq!(Letters::letter_of_owner_by_index((bob, 1)), letter_id);
+		assert_eq!(Letters::letters_of(&bob), vec![letter_id]);
 		assert_eq!(Letters::owner_of(letter_id), Some(bob));
 		assert_eq!(Letters::owned_letter_count(bob), 1);
 		assert_eq!(Letters::owned_letter_count(alice), 0);
 
 		let page2 = "𝔳𝔦𝔬𝔩𝔢𝔱𝔰 𝔞𝔯𝔢 𝔟𝔩𝔲𝔢 ❃".as_bytes().to_vec();
 		assert_ok!(Letters::write_page(bob_signed.clone(), letter_id, page2.clone()));
-		assert_eq!(Letters::read_page(letter_id, 1).unwrap(), page2);
+		assert_eq!(Letters::read_page(letter_id, 1).unwrap(), on_chain(&page2));
 		assert_eq!(
 			Balances::reserved_balance(&bob),
 			LETTER_DEPOSIT_BASE as u128 +
@@ -157,13 +186,59 @@ fn transfer_works() {
 // -------------------------------------------
 // error
 
+#[test]
+fn transfer_to_self_error() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::invite_contributor(
+			Origin::signed(alice),
+			letter_id,
+			bob,
+			Role::Writer
+		));
+
+		assert_noop!(
+			Letters::transfer(Origin::signed(alice), alice, letter_id),
+			Error::<Test>::CannotTransferToSelf
+		);
+		assert_noop!(
+			Letters::force_transfer(Origin::root(), letter_id, alice),
+			Error::<Test>::CannotTransferToSelf
+		);
+
+		assert_eq!(Letters::owned_letter_count(alice), 1);
+		assert_eq!(Letters::letters_of(&alice), vec![letter_id]);
+		assert_eq!(Letters::contributor_role(letter_id, &bob), Some(Role::Writer));
+
+		assert_ok!(Letters::create_book(Origin::signed(alice), b"book".to_vec()));
+		assert_noop!(
+			Letters::transfer_book(Origin::signed(alice), alice, 1),
+			Error::<Test>::CannotTransferToSelf
+		);
+	});
+}
+
 #[test]
 fn write_page_wrong_owner_error() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
 		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
 
-		assert_ok!(Letters::init_letter(Origin::signed(1), title.clone(), author.clone()));
+		assert_ok!(Letters::init_letter(
+			Origin::signed(1),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
 
 		let letter_id = Letters::letter_by_index(1);
 		let page = "𝔯𝔬𝔰𝔢𝔰 𝔞𝔯𝔢 𝔯𝔢𝔡 🌹".as_bytes().to_vec();
@@ -178,28 +253,33 @@ fn write_page_wrong_owner_error() {
 
 #[test]
 fn non_existent_page_error() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
 		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
 
-		assert_ok!(Letters::init_letter(Origin::signed(1), title.clone(), author.clone()));
+		assert_ok!(Letters::init_letter(
+			Origin::signed(1),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
 		let letter_id = Letters::letter_by_index(1);
 		let page = "𝔯𝔬𝔰𝔢𝔰 𝔞𝔯𝔢 𝔯𝔢𝔡 🌹".as_bytes().to_vec();
 
 		assert_ok!(Letters::write_page(Origin::signed(1), letter_id, page.clone()));
-		assert_eq!(Letters::read_page(letter_id, 0).unwrap().into_inner(), page);
+		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), on_chain(&page));
 
 		assert_noop!(Letters::read_page(letter_id, 1), Error::<Test>::NonExistentPage);
 
 		let page = "𝔳𝔦𝔬𝔩𝔢𝔱𝔰 𝔞𝔯𝔢 𝔟𝔩𝔲𝔢 ❃".as_bytes().to_vec();
 		assert_ok!(Letters::write_page(Origin::signed(1), letter_id, page.clone()));
-		assert_eq!(Letters::read_page(letter_id, 1).unwrap(), page);
+		assert_eq!(Letters::read_page(letter_id, 1).unwrap(), on_chain(&page));
 	});
 }
 
 #[test]
 fn buy_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
 		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
 
@@ -211,7 +291,12 @@ fn buy_works() {
 		assert_eq!(Balances::free_balance(&alice), ALICE_INIT_BALANCE);
 		assert_eq!(Balances::free_balance(&bob), BOB_INIT_BALANCE);
 
-		assert_ok!(Letters::init_letter(alice_signed.clone(), title.clone(), author.clone()));
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
 		let letter_id = Letters::letter_by_index(1);
 		assert_eq!(
 			Balances::free_balance(&alice),
@@ -222,7 +307,12 @@ fn buy_works() {
 		);
 
 		let price = 500u32;
-		assert_ok!(Letters::set_price(alice_signed.clone(), letter_id, price.into()));
+		assert_ok!(Letters::set_price(
+			alice_signed.clone(),
+			letter_id,
+			price.into(),
+			AssetKind::Native
+		));
 
 		assert_ok!(Letters::buy_letter(bob_signed, letter_id, 500u32.into()));
 		assert_eq!(
@@ -241,3 +331,3296 @@ fn buy_works() {
 		);
 	});
 }
+
+#[test]
+fn edition_works() {
+	build_and_execute(|| {
+		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
+		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+		let alice_signed = Origin::signed(alice);
+		let bob_signed = Origin::signed(bob);
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let master_id = Letters::letter_by_index(1);
+		let page = "𝔯𝔬𝔰𝔢𝔰 𝔞𝔯𝔢 𝔯𝔢𝔡 🌹".as_bytes().to_vec();
+		assert_ok!(Letters::write_page(alice_signed.clone(), master_id, page.clone()));
+
+		let print_price = 100u128;
+		assert_ok!(Letters::create_edition(alice_signed.clone(), master_id, 2, print_price));
+		let alice_free = Balances::free_balance(&alice);
+
+		assert_ok!(Letters::mint_print(bob_signed.clone(), master_id));
+		assert_eq!(Letters::edition_supply(master_id), 1);
+		assert_eq!(Balances::free_balance(&alice), alice_free + print_price);
+
+		let print_id = Letters::letter_by_index(2);
+		assert_eq!(Letters::print_of(print_id), Some((master_id, 1)));
+		assert_eq!(Letters::owner_of(print_id), Some(bob));
+		assert_eq!(Letters::letters_of(&bob), vec![print_id]);
+		assert_eq!(Letters::read_page(print_id, 0).unwrap(), on_chain(&page));
+		assert_eq!(
+			Balances::reserved_balance(&bob),
+			LETTER_DEPOSIT_BASE as u128 +
+				(title.len() as u32 * LETTER_DEPOSIT_FACTOR) as u128 +
+				(author.len() as u32 * LETTER_DEPOSIT_FACTOR) as u128
+		);
+
+		// the master is sealed after the first print
+		let (master, _) = Letters::letter(master_id).unwrap();
+		assert!(master.sealed);
+		assert_noop!(
+			Letters::write_page(alice_signed.clone(), master_id, page.clone()),
+			Error::<Test>::LetterSealed
+		);
+
+		// prints are tradeable like any other letter
+		assert_ok!(Letters::transfer(bob_signed, alice, print_id));
+		assert_eq!(Letters::owner_of(print_id), Some(alice));
+
+		// burning a print frees the master, but its number is not given out again
+		assert_eq!(Letters::live_prints(master_id), 1);
+		assert_noop!(
+			Letters::burn_letter(alice_signed.clone(), master_id),
+			Error::<Test>::MasterHasPrints
+		);
+		assert_ok!(Letters::burn_letter(alice_signed.clone(), print_id));
+		assert_eq!(Letters::live_prints(master_id), 0);
+		assert_ok!(Letters::mint_print(Origin::signed(bob), master_id));
+		let print_id = Letters::letter_by_index(2);
+		assert_eq!(Letters::print_of(print_id), Some((master_id, 2)));
+		assert_eq!(Letters::live_prints(master_id), 1);
+
+		assert_ok!(Letters::burn_letter(Origin::signed(bob), print_id));
+		assert_ok!(Letters::burn_letter(alice_signed, master_id));
+		assert_eq!(Letters::edition(master_id), None);
+	});
+}
+
+#[test]
+fn mint_print_deposit_error() {
+	build_and_execute(|| {
+		let alice = 1;
+		let carol = 3;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let master_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::create_edition(Origin::signed(alice), master_id, 2, 100));
+
+		// carol can pay for the print, but not for the deposit of the print
+		Balances::make_free_balance_be(&carol, 600);
+		assert_ok!(Balances::reserve(&carol, 400));
+		let alice_free = Balances::free_balance(&alice);
+		assert_noop!(
+			Letters::mint_print(Origin::signed(carol), master_id),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(Balances::free_balance(&alice), alice_free);
+		assert_eq!(Letters::edition_supply(master_id), 0);
+	});
+}
+
+#[test]
+fn edition_supply_exhausted_error() {
+	build_and_execute(|| {
+		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
+		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(1),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let master_id = Letters::letter_by_index(1);
+
+		assert_noop!(
+			Letters::mint_print(Origin::signed(2), master_id),
+			Error::<Test>::NonExistentEdition
+		);
+		assert_noop!(
+			Letters::create_edition(Origin::signed(2), master_id, 1, 0),
+			Error::<Test>::LetterNotOwned
+		);
+
+		assert_ok!(Letters::create_edition(Origin::signed(1), master_id, 1, 0));
+		assert_noop!(
+			Letters::create_edition(Origin::signed(1), master_id, 1, 0),
+			Error::<Test>::EditionAlreadyExists
+		);
+
+		assert_ok!(Letters::mint_print(Origin::signed(2), master_id));
+		assert_noop!(
+			Letters::mint_print(Origin::signed(2), master_id),
+			Error::<Test>::EditionSupplyExhausted
+		);
+
+		let print_id = Letters::letter_by_index(2);
+		assert_noop!(
+			Letters::create_edition(Origin::signed(2), print_id, 1, 0),
+			Error::<Test>::CannotEditionPrint
+		);
+	});
+}
+
+#[test]
+fn collection_works() {
+	build_and_execute(|| {
+		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
+		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
+		let description = "𝔞𝔫 𝔞𝔫𝔱𝔥𝔬𝔩𝔬𝔤𝔶".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+		let alice_signed = Origin::signed(alice);
+		let bob_signed = Origin::signed(bob);
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let first_id = Letters::letter_by_index(1);
+		let second_id = Letters::letter_by_index(2);
+
+		assert_ok!(Letters::create_collection(
+			bob_signed.clone(),
+			title.clone(),
+			description.clone()
+		));
+		assert_eq!(Letters::collections_count(), 1);
+		let (collection, _) = Letters::collection(1).unwrap();
+		assert_eq!(collection.curator, bob);
+		assert_eq!(collection.description, description);
+		assert_eq!(
+			Balances::reserved_balance(&bob),
+			LETTER_DEPOSIT_BASE as u128 +
+				(title.len() as u32 * LETTER_DEPOSIT_FACTOR) as u128 +
+				(description.len() as u32 * LETTER_DEPOSIT_FACTOR) as u128
+		);
+
+		// the curator proposes, the letter owner consents
+		assert_ok!(Letters::add_to_collection(bob_signed.clone(), 1, first_id));
+		assert!(Letters::collection_entries(1).is_empty());
+		assert_eq!(Letters::pending_entry(1, first_id), Some(bob));
+		assert_ok!(Letters::add_to_collection(alice_signed.clone(), 1, first_id));
+		assert_eq!(Letters::pending_entry(1, first_id), None);
+
+		// the letter owner proposes, the curator consents
+		assert_ok!(Letters::add_to_collection(alice_signed.clone(), 1, second_id));
+		assert_ok!(Letters::add_to_collection(bob_signed.clone(), 1, second_id));
+
+		assert_eq!(Letters::collection_entries(1).into_inner(), vec![first_id, second_id]);
+		assert_eq!(Letters::collections_of(first_id), vec![1]);
+
+		assert_ok!(Letters::move_in_collection(bob_signed.clone(), 1, second_id, 0));
+		assert_eq!(Letters::collection_entries(1).into_inner(), vec![second_id, first_id]);
+
+		assert_ok!(Letters::remove_from_collection(alice_signed, 1, second_id));
+		assert_eq!(Letters::collection_entries(1).into_inner(), vec![first_id]);
+		assert!(Letters::collections_of(second_id).is_empty());
+	});
+}
+
+#[test]
+fn collection_consent_error() {
+	build_and_execute(|| {
+		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
+		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(1),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::create_collection(Origin::signed(2), title.clone(), vec![]));
+
+		assert_noop!(
+			Letters::add_to_collection(Origin::signed(3), 1, letter_id),
+			Error::<Test>::NotCuratorOrOwner
+		);
+		assert_noop!(
+			Letters::add_to_collection(Origin::signed(1), 2, letter_id),
+			Error::<Test>::NonExistentCollection
+		);
+
+		// the curator's proposal alone does not add the letter
+		assert_ok!(Letters::add_to_collection(Origin::signed(2), 1, letter_id));
+		assert_ok!(Letters::add_to_collection(Origin::signed(2), 1, letter_id));
+		assert!(Letters::collection_entries(1).is_empty());
+		assert_eq!(Letters::pending_entry_count(1), 1);
+
+		// a proposal is withdrawn like an entry
+		assert_ok!(Letters::remove_from_collection(Origin::signed(2), 1, letter_id));
+		assert_eq!(Letters::pending_entry(1, letter_id), None);
+		assert_eq!(Letters::pending_entry_count(1), 0);
+		assert_noop!(
+			Letters::remove_from_collection(Origin::signed(2), 1, letter_id),
+			Error::<Test>::NotInCollection
+		);
+
+		assert_ok!(Letters::add_to_collection(Origin::signed(2), 1, letter_id));
+		assert_ok!(Letters::add_to_collection(Origin::signed(1), 1, letter_id));
+		assert_noop!(
+			Letters::add_to_collection(Origin::signed(1), 1, letter_id),
+			Error::<Test>::AlreadyInCollection
+		);
+		assert_noop!(
+			Letters::move_in_collection(Origin::signed(1), 1, letter_id, 0),
+			Error::<Test>::NotCurator
+		);
+		assert_noop!(
+			Letters::move_in_collection(Origin::signed(2), 1, letter_id, 1),
+			Error::<Test>::InvalidPosition
+		);
+	});
+}
+
+#[test]
+fn collection_pending_entries_error() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		for _ in 0..MaxPendingEntries::get() + 1 {
+			assert_ok!(Letters::init_letter(
+				Origin::signed(alice),
+				b"a".to_vec(),
+				b"b".to_vec(),
+				TransferPolicy::Transferable
+			));
+		}
+		assert_ok!(Letters::create_collection(Origin::signed(bob), b"a".to_vec(), vec![]));
+
+		// the curator cannot flood the collection with proposals
+		for index in 1..=MaxPendingEntries::get() as u64 {
+			let letter_id = Letters::letter_by_index(index);
+			assert_ok!(Letters::add_to_collection(Origin::signed(bob), 1, letter_id));
+		}
+		let last_id = Letters::letter_by_index(MaxPendingEntries::get() as u64 + 1);
+		assert_noop!(
+			Letters::add_to_collection(Origin::signed(bob), 1, last_id),
+			Error::<Test>::TooManyPendingEntries
+		);
+
+		// burning a letter withdraws its proposals
+		let first_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::burn_letter(Origin::signed(alice), first_id));
+		assert_eq!(Letters::pending_entry(1, first_id), None);
+		assert_eq!(Letters::pending_entry_count(1), MaxPendingEntries::get() - 1);
+		assert_ok!(Letters::add_to_collection(Origin::signed(bob), 1, last_id));
+	});
+}
+
+#[test]
+fn letter_entries_error() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			b"a".to_vec(),
+			b"b".to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		for _ in 0..MaxLetterEntries::get() + 1 {
+			assert_ok!(Letters::create_collection(Origin::signed(bob), b"a".to_vec(), vec![]));
+		}
+
+		// the letter cannot be spread over more collections than a burn can go through
+		for collection_id in 1..=MaxLetterEntries::get() as u64 {
+			assert_ok!(Letters::add_to_collection(Origin::signed(bob), collection_id, letter_id));
+		}
+		let last_id = MaxLetterEntries::get() as u64 + 1;
+		assert_noop!(
+			Letters::add_to_collection(Origin::signed(bob), last_id, letter_id),
+			Error::<Test>::TooManyLetterEntries
+		);
+
+		// accepting a proposal does not take another entry
+		assert_ok!(Letters::add_to_collection(Origin::signed(alice), 1, letter_id));
+		assert_eq!(Letters::letter_entry_count(letter_id), MaxLetterEntries::get());
+
+		// neither does a collection of the owner
+		assert_ok!(Letters::create_collection(Origin::signed(alice), b"a".to_vec(), vec![]));
+		let own_id = Letters::collections_count();
+		assert_noop!(
+			Letters::add_to_collection(Origin::signed(alice), own_id, letter_id),
+			Error::<Test>::TooManyLetterEntries
+		);
+
+		// leaving a collection frees an entry
+		assert_ok!(Letters::remove_from_collection(Origin::signed(alice), 1, letter_id));
+		assert_eq!(Letters::letter_entry_count(letter_id), MaxLetterEntries::get() - 1);
+		assert_ok!(Letters::add_to_collection(Origin::signed(bob), last_id, letter_id));
+
+		// and so does destroying a collection
+		assert_ok!(Letters::destroy_collection(Origin::signed(bob), 2));
+		assert_eq!(Letters::letter_entry_count(letter_id), MaxLetterEntries::get() - 1);
+
+		assert_ok!(Letters::burn_letter(Origin::signed(alice), letter_id));
+		assert_eq!(Letters::letter_entry_count(letter_id), 0);
+	});
+}
+
+#[test]
+fn destroy_collection_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		for _ in 0..2 {
+			assert_ok!(Letters::init_letter(
+				Origin::signed(alice),
+				"hello world".as_bytes().to_vec(),
+				"bear".as_bytes().to_vec(),
+				TransferPolicy::Transferable
+			));
+		}
+		let first_id = Letters::letter_by_index(1);
+		let second_id = Letters::letter_by_index(2);
+		let bob_reserved = Balances::reserved_balance(&bob);
+		assert_ok!(Letters::create_collection(Origin::signed(bob), b"a".to_vec(), vec![]));
+
+		assert_ok!(Letters::add_to_collection(Origin::signed(bob), 1, first_id));
+		assert_ok!(Letters::add_to_collection(Origin::signed(alice), 1, first_id));
+		assert_ok!(Letters::add_to_collection(Origin::signed(bob), 1, second_id));
+
+		assert_noop!(
+			Letters::destroy_collection(Origin::signed(alice), 1),
+			Error::<Test>::NotCurator
+		);
+		assert_ok!(Letters::destroy_collection(Origin::signed(bob), 1));
+		System::assert_last_event(crate::Event::CollectionDestroyed(bob, 1).into());
+
+		// the letters stay with alice, and bob gets the deposit back
+		assert_eq!(Letters::collection(1), None);
+		assert!(Letters::collection_entries(1).is_empty());
+		assert!(Letters::collections_of(first_id).is_empty());
+		assert_eq!(Letters::pending_entry(1, second_id), None);
+		assert_eq!(Letters::pending_entry_count(1), 0);
+		assert_eq!(Letters::owner_of(first_id), Some(alice));
+		assert_eq!(Balances::reserved_balance(&bob), bob_reserved);
+		assert_noop!(
+			Letters::destroy_collection(Origin::signed(bob), 1),
+			Error::<Test>::NonExistentCollection
+		);
+	});
+}
+
+#[test]
+fn book_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+		let chapter_title = "i".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+		let alice_signed = Origin::signed(alice);
+		let bob_signed = Origin::signed(bob);
+
+		assert_ok!(Letters::create_book(alice_signed.clone(), title.clone()));
+		let book_id = Letters::books_count();
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let first_id = Letters::letter_by_index(1);
+		let second_id = Letters::letter_by_index(2);
+
+		assert_ok!(Letters::add_chapter(
+			alice_signed.clone(),
+			book_id,
+			first_id,
+			chapter_title.clone()
+		));
+		assert_ok!(Letters::add_chapter(
+			alice_signed.clone(),
+			book_id,
+			second_id,
+			chapter_title.clone()
+		));
+		let (book, book_reserve) = Letters::book(book_id).unwrap();
+		assert_eq!(book.chapters.len(), 2);
+		assert_eq!(book.chapters[0].letter_id, first_id);
+		assert_eq!(book.chapters[1].title, chapter_title);
+		assert_eq!(
+			book_reserve,
+			LETTER_DEPOSIT_BASE as u128 +
+				(title.len() as u32 * LETTER_DEPOSIT_FACTOR) as u128 +
+				(2 * chapter_title.len() as u32 * LETTER_DEPOSIT_FACTOR) as u128
+		);
+
+		// chapters can't be sold on their own
+		assert_ok!(Letters::set_price(alice_signed.clone(), first_id, 100, AssetKind::Native));
+		assert_noop!(
+			Letters::buy_letter(bob_signed.clone(), first_id, 100),
+			Error::<Test>::ChapterNotDetached
+		);
+		assert_noop!(
+			Letters::transfer(alice_signed.clone(), bob, first_id),
+			Error::<Test>::ChapterNotDetached
+		);
+
+		assert_ok!(Letters::set_book_price(alice_signed.clone(), book_id, 300));
+		let reserved = Balances::reserved_balance(&alice);
+		assert_ok!(Letters::buy_book(bob_signed.clone(), book_id, 300));
+
+		let (book, _) = Letters::book(book_id).unwrap();
+		assert_eq!(book.owner, bob);
+		assert_eq!(Letters::owner_of(first_id), Some(bob));
+		assert_eq!(Letters::owner_of(second_id), Some(bob));
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+		assert_eq!(Balances::reserved_balance(&bob), reserved);
+
+		// once detached, a chapter is an ordinary letter again
+		assert_ok!(Letters::detach_chapter(bob_signed.clone(), book_id, first_id));
+		assert_eq!(Letters::chapter_of(first_id), None);
+		assert_ok!(Letters::transfer(bob_signed, alice, first_id));
+		assert_eq!(Letters::owner_of(first_id), Some(alice));
+	});
+}
+
+#[test]
+fn book_chapter_error() {
+	build_and_execute(|| {
+		let title = "𝔥𝔢𝔩𝔩𝔬 𝔴𝔬𝔯𝔩𝔡".as_bytes().to_vec();
+		let author = "𝖇𝖊𝖆𝖗".as_bytes().to_vec();
+
+		assert_ok!(Letters::create_book(Origin::signed(1), title.clone()));
+		assert_ok!(Letters::init_letter(
+			Origin::signed(1),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		assert_ok!(Letters::init_letter(
+			Origin::signed(2),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		let bob_letter_id = Letters::letter_by_index(2);
+
+		assert_noop!(
+			Letters::add_chapter(Origin::signed(2), 1, bob_letter_id, vec![]),
+			Error::<Test>::BookNotOwned
+		);
+		assert_noop!(
+			Letters::add_chapter(Origin::signed(1), 1, bob_letter_id, vec![]),
+			Error::<Test>::LetterNotOwned
+		);
+		assert_ok!(Letters::add_chapter(Origin::signed(1), 1, letter_id, vec![]));
+		assert_noop!(
+			Letters::add_chapter(Origin::signed(1), 1, letter_id, vec![]),
+			Error::<Test>::AlreadyChapter
+		);
+		assert_noop!(
+			Letters::detach_chapter(Origin::signed(1), 1, bob_letter_id),
+			Error::<Test>::NotChapter
+		);
+		assert_noop!(Letters::buy_book(Origin::signed(2), 1, 100), Error::<Test>::BookNotForSale);
+	});
+}
+
+#[test]
+fn book_chapter_policy_error() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+		let carol = 3;
+		let _ = Balances::deposit_creating(&carol, 1000);
+		let details = BlakeTwo256::hash(b"insults");
+
+		assert_ok!(Letters::create_book(Origin::signed(alice), b"book".to_vec()));
+		for policy in [TransferPolicy::Soulbound, TransferPolicy::Transferable] {
+			assert_ok!(Letters::init_letter(
+				Origin::signed(alice),
+				"hello world".as_bytes().to_vec(),
+				"bear".as_bytes().to_vec(),
+				policy
+			));
+		}
+		let soulbound_id = Letters::letter_by_index(1);
+		let letter_id = Letters::letter_by_index(2);
+
+		// a chapter that cannot change hands would keep the book from being sold
+		assert_noop!(
+			Letters::add_chapter(Origin::signed(alice), 1, soulbound_id, vec![]),
+			Error::<Test>::LetterSoulbound
+		);
+		assert_ok!(Letters::freeze(Origin::signed(alice), letter_id));
+		assert_noop!(
+			Letters::add_chapter(Origin::signed(alice), 1, letter_id, vec![]),
+			Error::<Test>::LetterFrozen
+		);
+		assert_ok!(Letters::thaw(Origin::signed(alice), letter_id));
+
+		// neither can a letter hidden from sale be added, nor a book with one be sold
+		for reporter in [bob, carol] {
+			assert_ok!(Letters::report_letter(
+				Origin::signed(reporter),
+				letter_id,
+				ReportReason::Spam,
+				details
+			));
+		}
+		assert_noop!(
+			Letters::add_chapter(Origin::signed(alice), 1, letter_id, vec![]),
+			Error::<Test>::LetterHidden
+		);
+		assert_ok!(Letters::resolve_report(Origin::root(), letter_id, bob, DepositAction::Refund));
+		assert_ok!(Letters::add_chapter(Origin::signed(alice), 1, letter_id, vec![]));
+		assert_ok!(Letters::report_letter(
+			Origin::signed(bob),
+			letter_id,
+			ReportReason::Spam,
+			details
+		));
+		assert!(Letters::is_hidden(letter_id));
+		assert_ok!(Letters::set_book_price(Origin::signed(alice), 1, 100));
+		assert_noop!(Letters::buy_book(Origin::signed(bob), 1, 100), Error::<Test>::LetterHidden);
+	});
+}
+
+#[test]
+fn destroy_book_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Letters::create_book(Origin::signed(alice), b"book".to_vec()));
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::add_chapter(Origin::signed(alice), 1, letter_id, b"one".to_vec()));
+		let (book, reserve) = Letters::book(1).unwrap();
+		assert_eq!(book.chapters[0].deposit, 15);
+		assert_eq!(reserve, 50 + 20 + 15);
+
+		// the chapter keeps the deposit it paid, whatever the deposit parameters are now
+		let mut doubled = Letters::deposit_parameters();
+		doubled.letter_factor *= 2;
+		assert_ok!(Letters::set_deposit_parameters(Origin::root(), doubled));
+
+		assert_noop!(Letters::destroy_book(Origin::signed(bob), 1), Error::<Test>::BookNotOwned);
+		assert_ok!(Letters::destroy_book(Origin::signed(alice), 1));
+		System::assert_last_event(crate::Event::BookDestroyed(alice, 1).into());
+		assert_eq!(Letters::book(1), None);
+		assert_eq!(Letters::chapter_of(letter_id), None);
+		assert_eq!(Letters::owner_of(letter_id), Some(alice));
+		assert_eq!(Balances::reserved_balance(alice), 125);
+
+		// the former chapter is an ordinary letter again
+		assert_ok!(Letters::transfer(Origin::signed(alice), bob, letter_id));
+	});
+}
+
+#[test]
+fn contributor_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+		let page = "roses are red".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+		let alice_signed = Origin::signed(alice);
+		let bob_signed = Origin::signed(bob);
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		let (_, letter_reserve) = Letters::letter(letter_id).unwrap();
+
+		assert_ok!(Letters::invite_contributor(alice_signed.clone(), letter_id, bob, Role::Writer));
+		assert_eq!(Letters::contributor_role(letter_id, &bob), Some(Role::Writer));
+
+		// the contributor pays for the page it writes
+		assert_ok!(Letters::write_page(bob_signed.clone(), letter_id, page.clone()));
+		assert_eq!(Letters::page_writer(letter_id, 0), Some(bob));
+		let page_deposit =
+			PAGE_DEPOSIT_BASE as u128 + (page.len() as u32 * PAGE_DEPOSIT_FACTOR) as u128;
+		assert_eq!(Balances::reserved_balance(&bob), page_deposit);
+		assert_eq!(
+			Letters::page_deposit(BlakeTwo256::hash(&page), (letter_id, 0)),
+			Some((bob, page_deposit))
+		);
+		assert_eq!(Balances::reserved_balance(&alice), letter_reserve);
+
+		assert_ok!(Letters::write_page(
+			alice_signed.clone(),
+			letter_id,
+			b"violets are blue".to_vec()
+		));
+		assert_eq!(Letters::page_writer(letter_id, 1), Some(alice));
+		assert_eq!(Balances::reserved_balance(&alice), letter_reserve + 26);
+
+		assert_ok!(Letters::revoke_contributor(alice_signed, letter_id, bob));
+		assert_noop!(
+			Letters::write_page(bob_signed, letter_id, page.clone()),
+			Error::<Test>::LetterNotOwned
+		);
+		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), on_chain(&page));
+	});
+}
+
+#[test]
+fn contributor_leaves_with_owner_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+		let carol = 3;
+		Balances::make_free_balance_be(&carol, 1000);
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		let (_, letter_reserve) = Letters::letter(letter_id).unwrap();
+
+		// carol pays for the page written off chain
+		assert_ok!(Letters::invite_contributor(
+			Origin::signed(alice),
+			letter_id,
+			carol,
+			Role::Editor
+		));
+		assert_ok!(Letters::write_offchain_page(
+			Origin::signed(carol),
+			letter_id,
+			content_id(b"roses are red")
+		));
+		let page_deposit = Balances::reserved_balance(&carol);
+		assert_eq!(Letters::contributor_deposit(letter_id, carol), page_deposit);
+
+		assert_ok!(Letters::set_price(Origin::signed(alice), letter_id, 100, AssetKind::Native));
+		assert_ok!(Letters::buy_letter(Origin::signed(bob), letter_id, 100));
+
+		// the editor of the seller cannot write on the letter of bob anymore
+		assert_eq!(Letters::contributor_role(letter_id, &carol), None);
+		assert_noop!(
+			Letters::write_page(Origin::signed(carol), letter_id, b"violets are blue".to_vec()),
+			Error::<Test>::LetterNotOwned
+		);
+
+		// bob took over the deposit of the page carol wrote
+		assert_eq!(Letters::contributor_deposit(letter_id, carol), 0);
+		assert_eq!(Balances::reserved_balance(&carol), 0);
+		assert_eq!(Balances::reserved_balance(&bob), letter_reserve + page_deposit);
+		assert_eq!(Letters::letter(letter_id).unwrap().1, letter_reserve + page_deposit);
+		assert_eq!(Letters::letter(letter_id).unwrap().0.price, 100);
+	});
+}
+
+#[test]
+fn contributor_roles_error() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(1),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		assert_noop!(
+			Letters::invite_contributor(Origin::signed(2), letter_id, 3, Role::Writer),
+			Error::<Test>::CannotManageContributor
+		);
+		assert_noop!(
+			Letters::invite_contributor(Origin::signed(1), letter_id, 1, Role::Writer),
+			Error::<Test>::AlreadyContributor
+		);
+
+		// editors manage writers, but not other editors
+		assert_ok!(Letters::invite_contributor(Origin::signed(1), letter_id, 2, Role::Editor));
+		assert_ok!(Letters::invite_contributor(Origin::signed(2), letter_id, 3, Role::Writer));
+		assert_noop!(
+			Letters::invite_contributor(Origin::signed(2), letter_id, 4, Role::Editor),
+			Error::<Test>::CannotManageContributor
+		);
+		assert_noop!(
+			Letters::invite_contributor(Origin::signed(2), letter_id, 3, Role::Writer),
+			Error::<Test>::AlreadyContributor
+		);
+		assert_ok!(Letters::revoke_contributor(Origin::signed(2), letter_id, 3));
+		assert_noop!(
+			Letters::revoke_contributor(Origin::signed(2), letter_id, 3),
+			Error::<Test>::NotContributor
+		);
+	});
+}
+
+#[test]
+fn revenue_split_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+		let carol = 3;
+		let dave = 4;
+		let alice_signed = Origin::signed(alice);
+		let bob_signed = Origin::signed(bob);
+
+		Balances::make_free_balance_be(&carol, 10000);
+		Balances::make_free_balance_be(&dave, 1000);
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		let third = Perbill::from_rational(1u32, 3u32);
+		assert_ok!(Letters::set_revenue_split(
+			alice_signed.clone(),
+			letter_id,
+			vec![(carol, third), (dave, third)]
+		));
+		assert_ok!(Letters::seal_letter(alice_signed.clone(), letter_id));
+		assert_noop!(
+			Letters::set_revenue_split(alice_signed.clone(), letter_id, vec![]),
+			Error::<Test>::LetterSealed
+		);
+
+		// an odd price can't be split evenly: the dust goes to the seller
+		let price = 1001u128;
+		let alice_free = Balances::free_balance(&alice);
+		assert_ok!(Letters::set_price(alice_signed, letter_id, price, AssetKind::Native));
+		assert_ok!(Letters::buy_letter(bob_signed.clone(), letter_id, price));
+
+		assert_eq!(Balances::free_balance(&carol), 10000 + 333);
+		assert_eq!(Balances::free_balance(&dave), 1000 + 333);
+		assert_eq!(Balances::free_balance(&alice), alice_free + 335);
+		let payouts = vec![(carol, 333), (dave, 333), (alice, 335)];
+		System::assert_has_event(crate::Event::<Test>::RevenueSplit(letter_id, payouts).into());
+
+		// later sales are not split
+		assert_ok!(Letters::transfer(bob_signed, alice, letter_id));
+		assert_ok!(Letters::buy_letter(Origin::signed(carol), letter_id, price));
+		assert_eq!(Balances::free_balance(&dave), 1000 + 333);
+	});
+}
+
+#[test]
+fn revenue_split_below_minimum_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+		let carol = 3;
+		let dave = 4;
+		let eve = 5;
+
+		Balances::make_free_balance_be(&dave, 1000);
+		Balances::make_free_balance_be(&eve, 5000);
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		let tenth = Perbill::from_percent(10);
+		assert_ok!(Letters::set_revenue_split(
+			Origin::signed(alice),
+			letter_id,
+			vec![(carol, tenth), (dave, tenth)]
+		));
+
+		// giving the letter away first does not skip the split of its first sale
+		assert_ok!(Letters::transfer(Origin::signed(alice), bob, letter_id));
+		assert_ok!(Letters::set_price(Origin::signed(bob), letter_id, 1000, AssetKind::Native));
+		let bob_free = Balances::free_balance(&bob);
+		assert_ok!(Letters::buy_letter(Origin::signed(eve), letter_id, 1000));
+
+		// carol has no account, and the share of carol is below the existential deposit
+		assert_eq!(Balances::total_balance(&carol), 0);
+		assert_eq!(Balances::free_balance(&dave), 1000 + 100);
+		assert_eq!(Balances::free_balance(&bob), bob_free + 900);
+		let payouts = vec![(dave, 100), (bob, 900)];
+		System::assert_has_event(crate::Event::<Test>::RevenueSplit(letter_id, payouts).into());
+	});
+}
+
+#[test]
+fn revenue_split_exceeds_total_error() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(1),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		let split = vec![(2, Perbill::from_percent(60)), (3, Perbill::from_percent(41))];
+		assert_noop!(
+			Letters::set_revenue_split(Origin::signed(1), letter_id, split),
+			Error::<Test>::SplitExceedsTotal
+		);
+		assert_noop!(
+			Letters::set_revenue_split(Origin::signed(2), letter_id, vec![]),
+			Error::<Test>::LetterNotOwned
+		);
+	});
+}
+
+#[test]
+fn fractionalize_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+		let alice_signed = Origin::signed(alice);
+		let bob_signed = Origin::signed(bob);
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		let (_, reserve) = Letters::letter(letter_id).unwrap();
+
+		assert_ok!(Letters::fractionalize(alice_signed.clone(), letter_id, 100));
+		let asset_id = Letters::fraction(letter_id).unwrap().asset_id;
+		assert_eq!(Letters::owner_of(letter_id), Some(Letters::account_id()));
+		assert_eq!(Letters::owned_letter_count(alice), 0);
+		assert_eq!(Assets::balance(asset_id, alice), 100);
+		// the deposit stays with alice while the letter is locked
+		assert_eq!(Balances::reserved_balance(&alice), reserve);
+
+		assert_ok!(Assets::transfer(alice_signed.clone(), asset_id, bob, 40));
+		assert_noop!(Letters::redeem(bob_signed.clone(), letter_id), Error::<Test>::NotAllShares);
+
+		assert_ok!(Assets::transfer(alice_signed, asset_id, bob, 60));
+		assert_ok!(Letters::redeem(bob_signed, letter_id));
+
+		assert_eq!(Letters::owner_of(letter_id), Some(bob));
+		assert_eq!(Letters::fraction(letter_id), None);
+		assert_eq!(Assets::maybe_total_supply(asset_id), None);
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+		assert_eq!(Balances::reserved_balance(&bob), reserve);
+
+		// every fractionalization gets its own asset, even within one block
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			title,
+			author,
+			TransferPolicy::Transferable
+		));
+		let other_id = Letters::letter_by_index(2);
+		assert_ok!(Letters::fractionalize(Origin::signed(bob), letter_id, 10));
+		assert_ok!(Letters::fractionalize(Origin::signed(alice), other_id, 10));
+		let first_asset = Letters::fraction(letter_id).unwrap().asset_id;
+		let other_asset = Letters::fraction(other_id).unwrap().asset_id;
+		assert_ne!(first_asset, asset_id);
+		assert_ne!(first_asset, other_asset);
+		assert_eq!(Assets::balance(other_asset, alice), 10);
+	});
+}
+
+#[test]
+fn fractionalized_letter_locked_error() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+		let page = "roses are red".as_bytes().to_vec();
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(1),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::set_price(Origin::signed(1), letter_id, 100, AssetKind::Native));
+		assert_ok!(Letters::create_edition(Origin::signed(1), letter_id, 2, 10));
+
+		assert_noop!(
+			Letters::fractionalize(Origin::signed(1), letter_id, 0),
+			Error::<Test>::ZeroShares
+		);
+		assert_ok!(Letters::fractionalize(Origin::signed(1), letter_id, 100));
+
+		// the price of a print would be paid to the pallet account
+		assert_noop!(
+			Letters::mint_print(Origin::signed(2), letter_id),
+			Error::<Test>::LetterFractionalized
+		);
+
+		assert_noop!(
+			Letters::write_page(Origin::signed(1), letter_id, page),
+			Error::<Test>::LetterFractionalized
+		);
+		assert_noop!(
+			Letters::set_price(Origin::signed(1), letter_id, 200, AssetKind::Native),
+			Error::<Test>::LetterFractionalized
+		);
+		assert_noop!(
+			Letters::transfer(Origin::signed(1), 2, letter_id),
+			Error::<Test>::LetterFractionalized
+		);
+		assert_noop!(
+			Letters::buy_letter(Origin::signed(2), letter_id, 100),
+			Error::<Test>::LetterFractionalized
+		);
+	});
+}
+
+#[test]
+fn buy_with_asset_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+		let stablecoin = 5;
+
+		let alice = 1;
+		let bob = 2;
+		let alice_signed = Origin::signed(alice);
+		let bob_signed = Origin::signed(bob);
+
+		assert_ok!(Assets::force_create(Origin::root(), stablecoin, alice, true, 1));
+		assert_ok!(Assets::mint(alice_signed.clone(), stablecoin, bob, 1000));
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::set_price(alice_signed, letter_id, 300, AssetKind::Asset(stablecoin)));
+
+		let alice_free = Balances::free_balance(&alice);
+		assert_noop!(
+			Letters::buy_letter(bob_signed.clone(), letter_id, 299),
+			"This letter is out of your budget!"
+		);
+		assert_ok!(Letters::buy_letter(bob_signed, letter_id, 300));
+
+		assert_eq!(Letters::owner_of(letter_id), Some(bob));
+		assert_eq!(Assets::balance(stablecoin, alice), 300);
+		assert_eq!(Assets::balance(stablecoin, bob), 700);
+		// the native balance of the seller only changes by the released deposit
+		let (_, reserve) = Letters::letter(letter_id).unwrap();
+		assert_eq!(Balances::free_balance(&alice), alice_free + reserve);
+	});
+}
+
+#[test]
+fn buy_with_asset_overflow_error() {
+	build_and_execute(|| {
+		let stablecoin = 5;
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Assets::force_create(Origin::root(), stablecoin, alice, true, 1));
+		assert_ok!(Assets::mint(Origin::signed(alice), stablecoin, bob, u64::MAX));
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		// the price does not fit in the balance of the asset, and is not rounded down to it
+		let price = u64::MAX as u128 + 1;
+		let asset = AssetKind::Asset(stablecoin);
+		assert_ok!(Letters::set_price(Origin::signed(alice), letter_id, price, asset));
+		assert_noop!(
+			Letters::buy_letter(Origin::signed(bob), letter_id, price),
+			Error::<Test>::PriceConversionOverflow
+		);
+	});
+}
+
+#[test]
+fn soulbound_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+		let alice_signed = Origin::signed(alice);
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Soulbound
+		));
+		let letter_id = Letters::letter_by_index(2);
+		assert_ok!(Letters::set_price(alice_signed.clone(), letter_id, 100, AssetKind::Native));
+
+		assert_noop!(
+			Letters::transfer(alice_signed.clone(), bob, letter_id),
+			Error::<Test>::LetterSoulbound
+		);
+		assert_noop!(
+			Letters::buy_letter(Origin::signed(bob), letter_id, 100),
+			Error::<Test>::LetterSoulbound
+		);
+		assert_noop!(
+			Letters::fractionalize(alice_signed.clone(), letter_id, 100),
+			Error::<Test>::LetterSoulbound
+		);
+
+		// the owner can still burn a soulbound letter
+		assert_ok!(Letters::burn_letter(alice_signed, letter_id));
+		assert_eq!(Letters::owner_of(letter_id), None);
+		assert_eq!(Letters::letter(letter_id), None);
+		assert_eq!(Letters::owned_letter_count(alice), 1);
+		assert_eq!(Letters::all_letters_count(), 1);
+		assert_eq!(
+			Balances::reserved_balance(&alice),
+			LETTER_DEPOSIT_BASE as u128 +
+				(title.len() as u32 * LETTER_DEPOSIT_FACTOR) as u128 +
+				(author.len() as u32 * LETTER_DEPOSIT_FACTOR) as u128
+		);
+	});
+}
+
+#[test]
+fn burn_letter_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+		let page = "roses are red".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+		let alice_signed = Origin::signed(alice);
+
+		for _ in 0..3 {
+			assert_ok!(Letters::init_letter(
+				alice_signed.clone(),
+				title.clone(),
+				author.clone(),
+				TransferPolicy::Transferable
+			));
+		}
+		let first_id = Letters::letter_by_index(1);
+		let burnt_id = Letters::letter_by_index(2);
+		let last_id = Letters::letter_by_index(3);
+
+		assert_ok!(Letters::invite_contributor(alice_signed.clone(), burnt_id, bob, Role::Writer));
+		assert_ok!(Letters::write_page(Origin::signed(bob), burnt_id, page));
+		assert!(Balances::reserved_balance(&bob) > 0);
+
+		assert_noop!(
+			Letters::burn_letter(Origin::signed(bob), burnt_id),
+			Error::<Test>::LetterNotOwned
+		);
+		assert_ok!(Letters::burn_letter(alice_signed.clone(), burnt_id));
+
+		// the last letter takes the freed index
+		assert_eq!(Letters::letter_by_index(1), first_id);
+		assert_eq!(Letters::letter_by_index(2), last_id);
+		assert!(Letters::letters_of(&alice).contains(&last_id));
+		assert_eq!(Letters::owned_letter_count(alice), 2);
+		assert_eq!(Letters::all_letters_count(), 2);
+		assert_eq!(Balances::reserved_balance(&bob), 0);
+		assert_eq!(Letters::contributors(burnt_id).len(), 0);
+
+		// a master with prints can not be burned
+		assert_ok!(Letters::create_edition(alice_signed.clone(), first_id, 2, 10));
+		assert_ok!(Letters::mint_print(Origin::signed(bob), first_id));
+		assert_noop!(Letters::burn_letter(alice_signed, first_id), Error::<Test>::MasterHasPrints);
+		assert_noop!(
+			Letters::burn_letter(Origin::signed(bob), burnt_id),
+			Error::<Test>::NonExistentLetter
+		);
+	});
+}
+
+#[test]
+fn letter_index_after_middle_letter_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		for _ in 0..3 {
+			assert_ok!(Letters::init_letter(
+				Origin::signed(alice),
+				"hello world".as_bytes().to_vec(),
+				"bear".as_bytes().to_vec(),
+				TransferPolicy::Transferable
+			));
+		}
+		let first_id = Letters::letter_by_index(1);
+		let middle_id = Letters::letter_by_index(2);
+		let last_id = Letters::letter_by_index(3);
+		let sorted = |mut ids: Vec<sp_core::H256>| {
+			ids.sort();
+			ids
+		};
+
+		// a transfer only moves the letter between owners
+		assert_ok!(Letters::transfer(Origin::signed(alice), bob, middle_id));
+		assert_eq!(sorted(Letters::letters_of(&alice)), sorted(vec![first_id, last_id]));
+		assert_eq!(Letters::letters_of(&bob), vec![middle_id]);
+		assert_eq!(Letters::owned_letter_count(alice), 2);
+		assert_eq!(Letters::owned_letter_count(bob), 1);
+		assert_eq!(Letters::letter_by_index(2), middle_id);
+
+		// burning a letter moves the last letter into its index
+		assert_ok!(Letters::burn_letter(Origin::signed(alice), first_id));
+		assert_eq!(Letters::letters_of(&alice), vec![last_id]);
+		assert_eq!(Letters::owned_letter_count(alice), 1);
+		assert_eq!(Letters::all_letters_count(), 2);
+		assert_eq!(Letters::letter_by_index(1), last_id);
+		assert_eq!(Letters::letter_by_index(2), middle_id);
+		assert_eq!(crate::AllLettersIndex::<Test>::get(last_id), 1);
+		assert_eq!(crate::AllLettersIndex::<Test>::get(middle_id), 2);
+		assert!(!crate::AllLettersIndex::<Test>::contains_key(first_id));
+		assert!(!crate::AllLettersArray::<Test>::contains_key(3));
+	});
+}
+
+#[test]
+fn freeze_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+		let page = "roses are red".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+		let alice_signed = Origin::signed(alice);
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::set_price(alice_signed.clone(), letter_id, 100, AssetKind::Native));
+
+		assert_ok!(Letters::freeze(alice_signed.clone(), letter_id));
+		assert_eq!(Letters::frozen(letter_id), Some(Freezer::Owner));
+
+		assert_noop!(
+			Letters::write_page(alice_signed.clone(), letter_id, page.clone()),
+			Error::<Test>::LetterFrozen
+		);
+		assert_noop!(
+			Letters::set_price(alice_signed.clone(), letter_id, 200, AssetKind::Native),
+			Error::<Test>::LetterFrozen
+		);
+		assert_noop!(
+			Letters::transfer(alice_signed.clone(), bob, letter_id),
+			Error::<Test>::LetterFrozen
+		);
+		assert_noop!(
+			Letters::buy_letter(Origin::signed(bob), letter_id, 100),
+			Error::<Test>::LetterFrozen
+		);
+
+		assert_ok!(Letters::thaw(alice_signed.clone(), letter_id));
+		assert_eq!(Letters::frozen(letter_id), None);
+		assert_ok!(Letters::write_page(alice_signed, letter_id, page));
+		assert_ok!(Letters::buy_letter(Origin::signed(bob), letter_id, 100));
+		assert_eq!(Letters::owner_of(letter_id), Some(bob));
+	});
+}
+
+#[test]
+fn freeze_force_origin_error() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+
+		let alice_signed = Origin::signed(1);
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		assert_noop!(Letters::freeze(Origin::signed(2), letter_id), Error::<Test>::LetterNotOwned);
+		assert_noop!(
+			Letters::thaw(alice_signed.clone(), letter_id),
+			Error::<Test>::LetterNotFrozen
+		);
+
+		// the owner can not thaw a letter frozen by the force origin
+		assert_ok!(Letters::freeze(Origin::root(), letter_id));
+		assert_eq!(Letters::frozen(letter_id), Some(Freezer::Force));
+		assert_noop!(Letters::freeze(alice_signed.clone(), letter_id), Error::<Test>::LetterFrozen);
+		assert_noop!(
+			Letters::thaw(alice_signed.clone(), letter_id),
+			Error::<Test>::FrozenByForceOrigin
+		);
+
+		assert_ok!(Letters::thaw(Origin::root(), letter_id));
+		assert_ok!(Letters::freeze(alice_signed, letter_id));
+		assert_ok!(Letters::thaw(Origin::root(), letter_id));
+	});
+}
+
+#[test]
+fn moderation_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+		let page = "roses are red".as_bytes().to_vec();
+		let bob_page = "violets are blue".as_bytes().to_vec();
+		let reason = BlakeTwo256::hash(b"abuse");
+
+		let alice = 1;
+		let bob = 2;
+		let alice_signed = Origin::signed(alice);
+		let letter_deposit = LETTER_DEPOSIT_BASE as u128 +
+			(title.len() as u32 * LETTER_DEPOSIT_FACTOR) as u128 +
+			(author.len() as u32 * LETTER_DEPOSIT_FACTOR) as u128;
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::invite_contributor(alice_signed.clone(), letter_id, bob, Role::Writer));
+		assert_ok!(Letters::write_page(alice_signed.clone(), letter_id, page.clone()));
+		assert_ok!(Letters::write_page(Origin::signed(bob), letter_id, bob_page.clone()));
+
+		// the deposit of the contributor page is slashed
+		let issuance = Balances::total_issuance();
+		assert_ok!(Letters::force_redact_page(
+			Origin::root(),
+			letter_id,
+			1,
+			reason,
+			DepositAction::Slash,
+			1
+		));
+		assert_eq!(Letters::read_page(letter_id, 1).unwrap(), Page::Redacted);
+		assert!(!Letters::verify_page(letter_id, 1, &[]).unwrap());
+		assert_eq!(Letters::redacted_page(letter_id, 1), Some(reason));
+		assert_eq!(Balances::reserved_balance(&bob), 0);
+		assert_eq!(
+			Balances::free_balance(&bob),
+			BOB_INIT_BALANCE -
+				PAGE_DEPOSIT_BASE as u128 -
+				(bob_page.len() as u32 * PAGE_DEPOSIT_FACTOR) as u128
+		);
+		assert!(Balances::total_issuance() < issuance);
+
+		// the deposit of the owner page is refunded
+		assert_ok!(Letters::force_redact_page(
+			Origin::root(),
+			letter_id,
+			0,
+			reason,
+			DepositAction::Refund,
+			1
+		));
+		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), Page::Redacted);
+		assert_eq!(Balances::reserved_balance(&alice), letter_deposit);
+		assert_eq!(Letters::letter(letter_id).unwrap().1, letter_deposit);
+
+		assert_ok!(Letters::force_burn_letter(
+			Origin::root(),
+			letter_id,
+			reason,
+			DepositAction::Slash
+		));
+		assert_eq!(Letters::owner_of(letter_id), None);
+		assert_eq!(Letters::redacted_page(letter_id, 0), None);
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+		assert_eq!(Balances::free_balance(&alice), ALICE_INIT_BALANCE - letter_deposit);
+	});
+}
+
+#[test]
+fn moderation_origin_error() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+		let page = "roses are red".as_bytes().to_vec();
+		let reason = BlakeTwo256::hash(b"abuse");
+
+		let alice_signed = Origin::signed(1);
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::write_page(alice_signed.clone(), letter_id, page));
+
+		assert_noop!(
+			Letters::force_redact_page(
+				alice_signed.clone(),
+				letter_id,
+				0,
+				reason,
+				DepositAction::Refund,
+				1
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Letters::force_burn_letter(alice_signed, letter_id, reason, DepositAction::Refund),
+			BadOrigin
+		);
+		assert_noop!(
+			Letters::force_redact_page(
+				Origin::root(),
+				letter_id,
+				1,
+				reason,
+				DepositAction::Refund,
+				1
+			),
+			Error::<Test>::NonExistentPage
+		);
+
+		assert_ok!(Letters::force_redact_page(
+			Origin::root(),
+			letter_id,
+			0,
+			reason,
+			DepositAction::Refund,
+			1
+		));
+		assert_noop!(
+			Letters::force_redact_page(
+				Origin::root(),
+				letter_id,
+				0,
+				reason,
+				DepositAction::Slash,
+				1
+			),
+			Error::<Test>::PageRedacted
+		);
+	});
+}
+
+#[test]
+fn force_burn_master_with_prints_error() {
+	build_and_execute(|| {
+		let reason = BlakeTwo256::hash(b"abuse");
+		let alice_signed = Origin::signed(1);
+		let bob = 2;
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let master_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::write_page(alice_signed.clone(), master_id, b"roses".to_vec()));
+		assert_ok!(Letters::create_edition(alice_signed, master_id, 2, 100));
+		assert_ok!(Letters::mint_print(Origin::signed(bob), master_id));
+		let print_id = Letters::letter_by_index(2);
+
+		// the print would be left reading the pages of a burnt master
+		assert_noop!(
+			Letters::force_burn_letter(Origin::root(), master_id, reason, DepositAction::Slash),
+			Error::<Test>::MasterHasPrints
+		);
+
+		assert_ok!(Letters::force_burn_letter(
+			Origin::root(),
+			print_id,
+			reason,
+			DepositAction::Refund
+		));
+		assert_eq!(Letters::live_prints(master_id), 0);
+		assert_ok!(Letters::force_burn_letter(
+			Origin::root(),
+			master_id,
+			reason,
+			DepositAction::Slash
+		));
+		assert_eq!(Letters::owner_of(master_id), None);
+		assert_eq!(Letters::edition(master_id), None);
+	});
+}
+
+#[test]
+fn report_letter_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+		let details = BlakeTwo256::hash(b"insults");
+
+		let alice = 1;
+		let bob = 2;
+		let carol = 3;
+		let _ = Balances::deposit_creating(&carol, 1000);
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::set_price(Origin::signed(alice), letter_id, 100, AssetKind::Native));
+
+		assert_ok!(Letters::report_letter(
+			Origin::signed(bob),
+			letter_id,
+			ReportReason::Abusive,
+			details
+		));
+		assert_eq!(Balances::reserved_balance(&bob), REPORT_BOND as u128);
+		assert!(!Letters::is_hidden(letter_id));
+
+		// reaching the threshold hides the letter from sale
+		assert_ok!(Letters::report_letter(
+			Origin::signed(carol),
+			letter_id,
+			ReportReason::Spam,
+			details
+		));
+		assert!(Letters::is_hidden(letter_id));
+		assert_noop!(
+			Letters::buy_letter(Origin::signed(bob), letter_id, 100),
+			Error::<Test>::LetterHidden
+		);
+
+		// an upheld report gets its bond back, a dismissed one is slashed
+		assert_ok!(Letters::resolve_report(Origin::root(), letter_id, bob, DepositAction::Refund));
+		assert_eq!(Balances::reserved_balance(&bob), 0);
+		assert_eq!(Balances::free_balance(&bob), BOB_INIT_BALANCE);
+		assert!(!Letters::is_hidden(letter_id));
+
+		assert_ok!(Letters::resolve_report(Origin::root(), letter_id, carol, DepositAction::Slash));
+		assert_eq!(Balances::reserved_balance(&carol), 0);
+		assert_eq!(Balances::free_balance(&carol), 1000 - REPORT_BOND as u128);
+		assert_eq!(Letters::reports(letter_id).len(), 0);
+
+		assert_ok!(Letters::buy_letter(Origin::signed(bob), letter_id, 100));
+	});
+}
+
+#[test]
+fn report_letter_error() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+		let details = BlakeTwo256::hash(b"insults");
+
+		let alice_signed = Origin::signed(1);
+
+		assert_ok!(Letters::init_letter(
+			alice_signed.clone(),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		assert_noop!(
+			Letters::report_letter(Origin::signed(2), details, ReportReason::Other, details),
+			Error::<Test>::NonExistentLetter
+		);
+		assert_ok!(Letters::report_letter(
+			Origin::signed(2),
+			letter_id,
+			ReportReason::Other,
+			details
+		));
+		assert_noop!(
+			Letters::report_letter(Origin::signed(2), letter_id, ReportReason::Spam, details),
+			Error::<Test>::AlreadyReported
+		);
+
+		for reporter in 3..=6 {
+			let _ = Balances::deposit_creating(&reporter, 1000);
+		}
+		for reporter in 3..=5 {
+			assert_ok!(Letters::report_letter(
+				Origin::signed(reporter),
+				letter_id,
+				ReportReason::Illegal,
+				details
+			));
+		}
+		assert_noop!(
+			Letters::report_letter(Origin::signed(6), letter_id, ReportReason::Illegal, details),
+			Error::<Test>::ReportCountOverflow
+		);
+
+		assert_noop!(
+			Letters::resolve_report(alice_signed.clone(), letter_id, 2, DepositAction::Slash),
+			BadOrigin
+		);
+		assert_noop!(
+			Letters::resolve_report(Origin::root(), letter_id, 6, DepositAction::Slash),
+			Error::<Test>::NonExistentReport
+		);
+
+		// burning the letter returns every pending bond
+		assert_ok!(Letters::burn_letter(alice_signed, letter_id));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Balances::free_balance(&5), 1000);
+	});
+}
+
+#[test]
+fn force_calls_work() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+		let letter_deposit = LETTER_DEPOSIT_BASE as u128 +
+			(title.len() as u32 * LETTER_DEPOSIT_FACTOR) as u128 +
+			(author.len() as u32 * LETTER_DEPOSIT_FACTOR) as u128;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			title.clone(),
+			author.clone(),
+			TransferPolicy::Soulbound
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		assert_noop!(
+			Letters::force_set_price(Origin::signed(alice), letter_id, 100, AssetKind::Native),
+			BadOrigin
+		);
+		assert_ok!(Letters::force_set_price(Origin::root(), letter_id, 100, AssetKind::Native));
+		assert_eq!(Letters::letter(letter_id).unwrap().0.price, 100);
+
+		assert_ok!(Letters::force_set_deposit(Origin::root(), letter_id, 100));
+		assert_eq!(Letters::letter(letter_id).unwrap().1, 100);
+		assert_eq!(Balances::reserved_balance(&alice), 100);
+		assert_ok!(Letters::force_set_deposit(Origin::root(), letter_id, letter_deposit));
+		assert_eq!(Balances::reserved_balance(&alice), letter_deposit);
+
+		// soulbound letters can still be moved by the force origin
+		assert_ok!(Letters::force_transfer(Origin::root(), letter_id, bob));
+		assert_eq!(Letters::owner_of(letter_id), Some(bob));
+		assert_eq!(Letters::owned_letter_count(alice), 0);
+		assert_eq!(Letters::letters_of(&bob), vec![letter_id]);
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+		assert_eq!(Balances::reserved_balance(&bob), letter_deposit);
+	});
+}
+
+#[test]
+fn force_calls_contributor_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+		let carol = 3;
+		Balances::make_free_balance_be(&carol, 1000);
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::invite_contributor(
+			Origin::signed(alice),
+			letter_id,
+			carol,
+			Role::Editor
+		));
+		assert_ok!(Letters::write_offchain_page(
+			Origin::signed(carol),
+			letter_id,
+			content_id(b"roses are red")
+		));
+		let page_deposit = Balances::reserved_balance(&carol);
+		assert!(page_deposit > 0);
+
+		// only the deposit of the owner is set
+		assert_ok!(Letters::force_set_deposit(Origin::root(), letter_id, 100));
+		assert_eq!(Letters::letter(letter_id).unwrap().1, 100);
+		assert_eq!(Balances::reserved_balance(&alice), 100);
+		assert_eq!(Letters::contributor_deposit(letter_id, carol), page_deposit);
+		assert_eq!(Balances::reserved_balance(&carol), page_deposit);
+
+		// the contributors of alice do not follow the letter to bob
+		assert_ok!(Letters::force_transfer(Origin::root(), letter_id, bob));
+		assert_eq!(Letters::contributor_role(letter_id, &carol), None);
+		assert_eq!(Letters::contributor_deposit(letter_id, carol), 0);
+		assert_eq!(Balances::reserved_balance(&carol), 0);
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+		assert_eq!(Balances::reserved_balance(&bob), 100 + page_deposit);
+		assert_eq!(Letters::letter(letter_id).unwrap().1, 100 + page_deposit);
+	});
+}
+
+#[test]
+fn force_rebuild_owner_index_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+
+		for _ in 0..3 {
+			assert_ok!(Letters::init_letter(
+				Origin::signed(alice),
+				title.clone(),
+				author.clone(),
+				TransferPolicy::Transferable
+			));
+		}
+		let first_id = Letters::letter_by_index(1);
+		let second_id = Letters::letter_by_index(2);
+		let third_id = Letters::letter_by_index(3);
+
+		// corrupt the owner index of alice and bob
+		crate::OwnedLetters::<Test>::remove(alice, second_id);
+		crate::OwnedLetters::<Test>::insert(bob, third_id, ());
+		crate::OwnedLettersCount::<Test>::insert(alice, 5);
+		assert!(Letters::do_try_state().is_err());
+
+		assert_noop!(
+			Letters::force_rebuild_owner_index(Origin::signed(alice), alice, 3),
+			BadOrigin
+		);
+		assert_noop!(
+			Letters::force_rebuild_owner_index(Origin::root(), alice, 2),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(Letters::force_rebuild_owner_index(Origin::root(), alice, 3));
+
+		assert_eq!(Letters::owned_letter_count(alice), 3);
+		let mut owned = Letters::letters_of(&alice);
+		owned.sort();
+		let mut expected = vec![first_id, second_id, third_id];
+		expected.sort();
+		assert_eq!(owned, expected);
+
+		assert_ok!(Letters::force_rebuild_owner_index(Origin::root(), bob, 3));
+		assert!(Letters::letters_of(&bob).is_empty());
+		assert_ok!(Letters::do_try_state());
+
+		// transfers work again on the repaired index
+		assert_ok!(Letters::transfer(Origin::signed(alice), bob, second_id));
+		assert_eq!(Letters::owned_letter_count(alice), 2);
+		assert!(!Letters::letters_of(&alice).contains(&second_id));
+		assert_eq!(Letters::letters_of(&bob), vec![second_id]);
+	});
+}
+
+#[test]
+fn try_state_detects_corruption() {
+	new_test_ext().execute_with(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+
+		for _ in 0..2 {
+			assert_ok!(Letters::init_letter(
+				Origin::signed(1),
+				title.clone(),
+				author.clone(),
+				TransferPolicy::Transferable
+			));
+		}
+		assert_ok!(Letters::do_try_state());
+
+		let letter_id = Letters::letter_by_index(2);
+		crate::OwnedLetters::<Test>::remove(1, letter_id);
+		assert!(Letters::do_try_state().is_err());
+
+		crate::OwnedLetters::<Test>::insert(1, letter_id, ());
+		assert_ok!(Letters::do_try_state());
+
+		// a deposit that is not actually reserved
+		let (letter, reserve) = Letters::letter(letter_id).unwrap();
+		crate::Letters::<Test>::insert(letter_id, (letter, reserve * 10));
+		assert!(Letters::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn migrate_owner_index_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+
+		for who in [alice, alice, bob] {
+			assert_ok!(Letters::init_letter(
+				Origin::signed(who),
+				title.clone(),
+				author.clone(),
+				TransferPolicy::Transferable
+			));
+		}
+
+		// roll the owner index back to the array maps of version 0
+		let _ = crate::OwnedLetters::<Test>::remove_all(None);
+		let _ = crate::OwnedLettersCount::<Test>::remove_all(None);
+		let first_id = Letters::letter_by_index(1);
+		let array_key = Twox64Concat::hash(&(alice, 1u64).encode());
+		let index_key = Twox64Concat::hash(&first_id.encode());
+		put_storage_value(b"Letters", b"OwnedLettersArray", &array_key, first_id);
+		put_storage_value(b"Letters", b"OwnedLettersIndex", &index_key, 1u64);
+		StorageVersion::new(0).put::<Letters>();
+
+		crate::migrations::v1::migrate::<Test>();
+
+		assert_eq!(Letters::on_chain_storage_version(), 1);
+		assert_eq!(Letters::owned_letter_count(alice), 2);
+		assert_eq!(Letters::owned_letter_count(bob), 1);
+		assert_eq!(Letters::letters_of(&alice).len(), 2);
+		assert_eq!(Letters::letters_of(&bob), vec![Letters::letter_by_index(3)]);
+		assert!(!have_storage_value(b"Letters", b"OwnedLettersArray", &array_key));
+		assert!(!have_storage_value(b"Letters", b"OwnedLettersIndex", &index_key));
+
+		// the migration only runs once
+		let _ = crate::OwnedLetters::<Test>::remove_all(None);
+		crate::migrations::v1::migrate::<Test>();
+		assert_eq!(Letters::letters_of(&alice).len(), 0);
+		assert_ok!(Letters::force_rebuild_owner_index(Origin::root(), alice, 3));
+		assert_ok!(Letters::force_rebuild_owner_index(Origin::root(), bob, 3));
+	});
+}
+
+#[test]
+fn letter_id_works() {
+	build_and_execute(|| {
+		let title = "hello world".as_bytes().to_vec();
+		let author = "bear".as_bytes().to_vec();
+
+		let alice = 1;
+		let bob = 2;
+
+		for _ in 0..2 {
+			assert_ok!(Letters::init_letter(
+				Origin::signed(alice),
+				title.clone(),
+				author.clone(),
+				TransferPolicy::Transferable
+			));
+		}
+
+		// the same title and author still give distinct ids
+		let first_id = Letters::letter_by_index(1);
+		let second_id = Letters::letter_by_index(2);
+		assert_ne!(first_id, second_id);
+		assert_eq!(first_id, Letters::letter_id_of(0));
+		assert_eq!(second_id, Letters::letter_id_of(1));
+		assert_eq!(Letters::get_nonce(), 2);
+
+		// ids can be derived off-chain from the sequence number alone
+		let preimage = [b"letterid".as_slice(), &0u64.to_le_bytes()].concat();
+		assert_eq!(first_id, BlakeTwo256::hash(&preimage));
+
+		// prints share the sequence of letters
+		assert_ok!(Letters::create_edition(Origin::signed(alice), first_id, 1, 0));
+		assert_ok!(Letters::mint_print(Origin::signed(bob), first_id));
+		assert_eq!(Letters::owner_of(Letters::letter_id_of(2)), Some(bob));
+
+		// the id of a burned letter is never handed out again
+		assert_ok!(Letters::burn_letter(Origin::signed(alice), second_id));
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			title,
+			author,
+			TransferPolicy::Transferable
+		));
+		assert_eq!(Letters::owner_of(second_id), None);
+		assert_eq!(Letters::owner_of(Letters::letter_id_of(3)), Some(alice));
+	});
+}
+
+#[test]
+fn letter_id_nonce_overflow_error() {
+	build_and_execute(|| {
+		crate::Nonce::<Test>::put(u64::MAX);
+
+		assert_noop!(
+			Letters::init_letter(
+				Origin::signed(1),
+				"hello world".as_bytes().to_vec(),
+				"bear".as_bytes().to_vec(),
+				TransferPolicy::Transferable
+			),
+			Error::<Test>::NonceOverflow
+		);
+	});
+}
+
+// A genesis letter owned by Alice, with the given pages.
+fn genesis_letter(
+	pages: Vec<&str>,
+) -> GenesisLetter<u64, u128, <Test as frame_system::Config>::Hash> {
+	GenesisLetter {
+		title: "hello world".as_bytes().to_vec(),
+		author: "bear".as_bytes().to_vec(),
+		pages: pages.into_iter().map(|page| page.as_bytes().to_vec()).collect(),
+		owner: 1,
+		price: 0,
+		sealed: false,
+		id: None,
+	}
+}
+
+#[test]
+fn genesis_letters_work() {
+	let mut sealed = genesis_letter(vec!["roses are red"]);
+	sealed.owner = 2;
+	sealed.price = 100;
+	sealed.sealed = true;
+	sealed.id = Some(Letters::letter_id_of(1));
+
+	genesis_test_ext(vec![genesis_letter(vec!["roses are red", "violets are blue"]), sealed])
+		.execute_with(|| {
+			assert_eq!(Letters::all_letters_count(), 2);
+			assert_eq!(Letters::get_nonce(), 2);
+
+			let first_id = Letters::letter_id_of(0);
+			assert_eq!(Letters::owner_of(first_id), Some(1));
+			assert_eq!(Letters::read_page(first_id, 1).unwrap(), on_chain(b"violets are blue"));
+			// letter deposit plus the deposit of each page
+			assert_eq!(Balances::reserved_balance(1), 125 + 23 + 26);
+
+			let (letter, _) = Letters::letter(Letters::letter_id_of(1)).unwrap();
+			assert_eq!(Letters::owner_of(letter.id), Some(2));
+			assert_eq!(letter.price, 100);
+			assert!(letter.sealed);
+
+			// runtime minting carries on the same sequence
+			assert_ok!(Letters::init_letter(
+				Origin::signed(1),
+				"hello world".as_bytes().to_vec(),
+				"bear".as_bytes().to_vec(),
+				TransferPolicy::Transferable
+			));
+			assert_eq!(Letters::owner_of(Letters::letter_id_of(2)), Some(1));
+
+			Letters::do_try_state().unwrap();
+		});
+}
+
+#[test]
+#[should_panic(expected = "Invalid genesis letter 1: duplicate id")]
+fn genesis_letters_duplicate_id_error() {
+	let mut duplicate = genesis_letter(vec![]);
+	duplicate.id = Some(Letters::letter_id_of(0));
+
+	genesis_test_ext(vec![genesis_letter(vec![]), duplicate]);
+}
+
+#[test]
+#[should_panic(expected = "Invalid genesis letter 0: too many pages")]
+fn genesis_letters_overflow_error() {
+	genesis_test_ext(vec![genesis_letter(vec!["page"; 65])]);
+}
+
+// Bounds a list of pages for `write_pages`.
+fn bounded_pages(pages: Vec<&str>) -> BoundedVec<BoundedVec<u8, MaxPageLength>, MaxPageNum> {
+	let pages: Vec<BoundedVec<u8, MaxPageLength>> =
+		pages.into_iter().map(|page| page.as_bytes().to_vec().try_into().unwrap()).collect();
+	pages.try_into().unwrap()
+}
+
+#[test]
+fn write_pages_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		assert_ok!(Letters::write_pages(
+			Origin::signed(alice),
+			letter_id,
+			bounded_pages(vec!["roses are red", "violets are blue"])
+		));
+		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), on_chain(b"roses are red"));
+		assert_eq!(Letters::read_page(letter_id, 1).unwrap(), on_chain(b"violets are blue"));
+		assert_eq!(Letters::page_writer(letter_id, 1), Some(alice));
+		// letter deposit plus the deposit of each page, which is held in the page store
+		assert_eq!(Balances::reserved_balance(alice), 125 + 23 + 26);
+		assert_eq!(Letters::letter(letter_id).unwrap().1, 125);
+
+		// contributors hold the deposit of the pages they write
+		assert_ok!(Letters::invite_contributor(
+			Origin::signed(alice),
+			letter_id,
+			bob,
+			Role::Writer
+		));
+		assert_ok!(Letters::write_pages(
+			Origin::signed(bob),
+			letter_id,
+			bounded_pages(vec!["sugar is sweet"])
+		));
+		assert_eq!(Letters::read_page(letter_id, 2).unwrap(), on_chain(b"sugar is sweet"));
+		assert_eq!(Letters::page_writer(letter_id, 2), Some(bob));
+		assert_eq!(Balances::reserved_balance(bob), 24);
+	});
+}
+
+#[test]
+fn write_pages_error() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		assert_noop!(
+			Letters::write_pages(Origin::signed(bob), letter_id, bounded_pages(vec!["roses"])),
+			Error::<Test>::LetterNotOwned
+		);
+
+		// nothing is written when the pages do not all fit in the letter
+		assert_ok!(Letters::write_pages(
+			Origin::signed(alice),
+			letter_id,
+			bounded_pages(vec![""; 60])
+		));
+		assert_noop!(
+			Letters::write_pages(Origin::signed(alice), letter_id, bounded_pages(vec![""; 5])),
+			Error::<Test>::PageCountOverflow
+		);
+
+		// nor when the owner cannot reserve their deposit
+		let long_pages: Vec<String> = ["a", "b", "c", "d"].iter().map(|c| c.repeat(300)).collect();
+		assert_noop!(
+			Letters::write_pages(
+				Origin::signed(alice),
+				letter_id,
+				bounded_pages(long_pages.iter().map(|page| page.as_str()).collect())
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Letters::seal_letter(Origin::signed(alice), letter_id));
+		assert_noop!(
+			Letters::write_pages(Origin::signed(alice), letter_id, bounded_pages(vec!["roses"])),
+			Error::<Test>::LetterSealed
+		);
+	});
+}
+
+#[test]
+fn append_to_page_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		// the first chunk starts a page, the next ones only pay for their bytes
+		assert_ok!(Letters::append_to_page(Origin::signed(alice), letter_id, b"roses".to_vec()));
+		System::assert_last_event(crate::Event::PageAppended(alice, letter_id, 0, 0).into());
+		assert_ok!(Letters::append_to_page(Origin::signed(alice), letter_id, b" are red".to_vec()));
+		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), on_chain(b"roses are red"));
+		assert_eq!(Balances::reserved_balance(alice), 125 + 23);
+		assert_eq!(Letters::page_store(BlakeTwo256::hash(b"roses")), None);
+
+		// pages written by someone else are never extended
+		assert_ok!(Letters::invite_contributor(
+			Origin::signed(alice),
+			letter_id,
+			bob,
+			Role::Writer
+		));
+		assert_ok!(Letters::append_to_page(Origin::signed(bob), letter_id, b"violets".to_vec()));
+		System::assert_last_event(crate::Event::PageAppended(bob, letter_id, 1, 1).into());
+		assert_eq!(Letters::page_writer(letter_id, 1), Some(bob));
+		assert_eq!(Balances::reserved_balance(bob), 17);
+
+		// a full page spills over into a new one
+		Balances::make_free_balance_be(&alice, 20_000);
+		let almost_full = vec![b'a'; MaxPageLength::get() as usize - 2];
+		assert_ok!(Letters::write_page(Origin::signed(alice), letter_id, almost_full));
+		let reserved = Balances::reserved_balance(alice);
+		assert_ok!(Letters::append_to_page(Origin::signed(alice), letter_id, b"abcd".to_vec()));
+		System::assert_last_event(crate::Event::PageAppended(alice, letter_id, 2, 3).into());
+		let full = [vec![b'a'; MaxPageLength::get() as usize - 2], b"ab".to_vec()].concat();
+		assert_eq!(Letters::read_page(letter_id, 2).unwrap(), on_chain(&full));
+		assert_eq!(Letters::read_page(letter_id, 3).unwrap(), on_chain(b"cd"));
+		assert_eq!(Balances::reserved_balance(alice), reserved + 2 + 12);
+
+		// a page filled to the brim is left alone and the next chunk starts a new page
+		let rest = vec![b'b'; MaxPageLength::get() as usize - 2];
+		assert_ok!(Letters::append_to_page(Origin::signed(alice), letter_id, rest));
+		System::assert_last_event(crate::Event::PageAppended(alice, letter_id, 3, 3).into());
+		let full_hash = Letters::letter(letter_id).unwrap().0.pages[3];
+		let reserved = Balances::reserved_balance(alice);
+		assert_ok!(Letters::append_to_page(Origin::signed(alice), letter_id, b"x".to_vec()));
+		System::assert_last_event(crate::Event::PageAppended(alice, letter_id, 4, 4).into());
+		assert_eq!(Letters::letter(letter_id).unwrap().0.pages[3], full_hash);
+		assert_eq!(Letters::read_page(letter_id, 4).unwrap(), on_chain(b"x"));
+		assert_eq!(Balances::reserved_balance(alice), reserved + 11);
+	});
+}
+
+#[test]
+fn append_to_shared_page_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+		let page_hash = BlakeTwo256::hash(b"roses are red");
+
+		for who in [alice, bob] {
+			assert_ok!(Letters::init_letter(
+				Origin::signed(who),
+				"hello world".as_bytes().to_vec(),
+				"bear".as_bytes().to_vec(),
+				TransferPolicy::Transferable
+			));
+			let letter_id = Letters::letter_by_index(who);
+			assert_ok!(Letters::append_to_page(
+				Origin::signed(who),
+				letter_id,
+				b"roses are red".to_vec()
+			));
+		}
+		let first_id = Letters::letter_by_index(1);
+		let second_id = Letters::letter_by_index(2);
+		assert_eq!(Letters::page_store(page_hash).unwrap().refs, 2);
+
+		// extending a page whose bytes are shared only reserves the added bytes
+		System::reset_events();
+		assert_ok!(Letters::append_to_page(Origin::signed(bob), second_id, b"!".to_vec()));
+		let balance_events: Vec<_> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				Event::Balances(event) => Some(event),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(balance_events, vec![pallet_balances::Event::Reserved { who: bob, amount: 1 }]);
+
+		let extended_hash = BlakeTwo256::hash(b"roses are red!");
+		assert_eq!(Letters::page_deposit(page_hash, (second_id, 0)), None);
+		assert_eq!(Letters::page_deposit(extended_hash, (second_id, 0)), Some((bob, 24)));
+		assert_eq!(Balances::reserved_balance(bob), 125 + 24);
+
+		// the other letter keeps the bytes and its own deposit
+		assert_eq!(Letters::page_store(page_hash).unwrap().refs, 1);
+		assert_eq!(Letters::read_page(first_id, 0).unwrap(), on_chain(b"roses are red"));
+		assert_eq!(Letters::page_deposit(page_hash, (first_id, 0)), Some((alice, 23)));
+		assert_eq!(Balances::reserved_balance(alice), 125 + 23);
+		assert_eq!(Letters::letter_bytes(second_id), 14);
+	});
+}
+
+#[test]
+fn append_to_page_error() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		assert_noop!(
+			Letters::append_to_page(Origin::signed(bob), letter_id, b"roses".to_vec()),
+			Error::<Test>::LetterNotOwned
+		);
+		assert_noop!(
+			Letters::append_to_page(
+				Origin::signed(alice),
+				letter_id,
+				vec![b'a'; MaxPageLength::get() as usize + 1]
+			),
+			Error::<Test>::PageLenOverflow
+		);
+		assert_noop!(
+			Letters::append_to_page(Origin::signed(alice), letter_id, Vec::new()),
+			Error::<Test>::NothingToAppend
+		);
+
+		// appending after a page of another writer needs a new page
+		assert_ok!(Letters::invite_contributor(
+			Origin::signed(alice),
+			letter_id,
+			bob,
+			Role::Writer
+		));
+		assert_ok!(Letters::write_pages(
+			Origin::signed(bob),
+			letter_id,
+			bounded_pages(vec![""; 64])
+		));
+		assert_noop!(
+			Letters::append_to_page(Origin::signed(alice), letter_id, b"roses".to_vec()),
+			Error::<Test>::PageCountOverflow
+		);
+
+		assert_ok!(Letters::seal_letter(Origin::signed(alice), letter_id));
+		assert_noop!(
+			Letters::append_to_page(Origin::signed(bob), letter_id, b"roses".to_vec()),
+			Error::<Test>::LetterSealed
+		);
+	});
+}
+
+#[test]
+fn deposit_holder_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::write_page(
+			Origin::signed(alice),
+			letter_id,
+			b"roses are red".to_vec()
+		));
+		assert_eq!(Letters::deposit_holder(letter_id), Some(alice));
+
+		// the whole reserve follows the letter, while the page stays paid by whoever stored it
+		assert_ok!(Letters::transfer(Origin::signed(alice), bob, letter_id));
+		assert_eq!(Letters::deposit_holder(letter_id), Some(bob));
+		assert_eq!(Balances::reserved_balance(alice), 23);
+		assert_eq!(Balances::reserved_balance(bob), 125);
+		assert_eq!(Balances::free_balance(alice), ALICE_INIT_BALANCE - 23);
+
+		// and later pages are charged to the new holder only
+		assert_ok!(Letters::write_page(
+			Origin::signed(bob),
+			letter_id,
+			b"violets are blue".to_vec()
+		));
+		assert_eq!(Balances::reserved_balance(bob), 125 + 26);
+		assert_eq!(Letters::letter(letter_id).unwrap().1, 125);
+
+		assert_ok!(Letters::force_set_deposit(Origin::root(), letter_id, 100));
+		assert_eq!(Balances::reserved_balance(bob), 100 + 26);
+
+		assert_ok!(Letters::burn_letter(Origin::signed(bob), letter_id));
+		assert_eq!(Letters::deposit_holder(letter_id), None);
+		assert_eq!(Balances::reserved_balance(bob), 0);
+		assert_eq!(Balances::reserved_balance(alice), 0);
+	});
+}
+
+#[test]
+fn deposit_insufficient_balance_error() {
+	build_and_execute(|| {
+		let alice = 1;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		// the reserve already held is left untouched when the next deposit cannot be paid
+		assert_noop!(
+			Letters::write_page(
+				Origin::signed(alice),
+				letter_id,
+				vec![b'a'; MaxPageLength::get() as usize]
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(Balances::reserved_balance(alice), 125);
+
+		// nor when the new owner cannot pay for it
+		assert_noop!(
+			Letters::transfer(Origin::signed(alice), 3, letter_id),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn migrate_deposit_holder_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		for _ in 0..2 {
+			assert_ok!(Letters::init_letter(
+				Origin::signed(alice),
+				"hello world".as_bytes().to_vec(),
+				"bear".as_bytes().to_vec(),
+				TransferPolicy::Transferable
+			));
+		}
+		let first_id = Letters::letter_by_index(1);
+		let second_id = Letters::letter_by_index(2);
+		assert_ok!(Letters::transfer(Origin::signed(alice), bob, second_id));
+
+		// roll back to version 1, which did not record deposit holders
+		let _ = crate::DepositHolder::<Test>::remove_all(None);
+		StorageVersion::new(1).put::<Letters>();
+
+		crate::migrations::v2::migrate::<Test>();
+
+		assert_eq!(Letters::on_chain_storage_version(), 2);
+		assert_eq!(Letters::deposit_holder(first_id), Some(alice));
+		assert_eq!(Letters::deposit_holder(second_id), Some(bob));
+	});
+}
+
+#[test]
+fn migrate_page_store_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		// roll back to the baseline layout, which kept the bytes of the pages in the letter and
+		// had no price asset, transfer policy nor seal; alice wrote two pages held in the
+		// reserve of the letter and bob, as a contributor, wrote a copy of the first one
+		let (letter, reserve) = Letters::letter(letter_id).unwrap();
+		let pages = bounded_pages(vec!["roses are red", "roses are red", "violets are blue"]);
+		let old = (letter.id, letter.title, letter.author, letter.price, pages);
+		for (index, writer) in [alice, bob, alice].into_iter().enumerate() {
+			crate::PageWriter::<Test>::insert(letter_id, index as u32, writer);
+		}
+		crate::ContributorDeposits::<Test>::insert(letter_id, bob, 23);
+		assert_ok!(Balances::reserve(&alice, 23 + 26));
+		assert_ok!(Balances::reserve(&bob, 23));
+		let key = crate::Letters::<Test>::hashed_key_for(letter_id);
+		frame_support::storage::unhashed::put(&key, &(old, reserve + 23 + 26));
+		StorageVersion::new(2).put::<Letters>();
+
+		crate::migrations::v3::migrate::<Test>();
+
+		assert_eq!(Letters::on_chain_storage_version(), 3);
+		let (letter, reserve) = Letters::letter(letter_id).unwrap();
+		assert_eq!(reserve, 125);
+		assert_eq!(letter.price_asset, AssetKind::Native);
+		assert_eq!(letter.transfer_policy, TransferPolicy::Transferable);
+		assert!(!letter.sealed);
+		assert_eq!(Letters::read_page(letter_id, 1).unwrap(), on_chain(b"roses are red"));
+		let roses = BlakeTwo256::hash(b"roses are red");
+		let violets = BlakeTwo256::hash(b"violets are blue");
+		assert_eq!(Letters::page_store(roses).unwrap().refs, 2);
+		assert_eq!(Letters::page_store(violets).unwrap().refs, 1);
+		assert_eq!(Letters::page_deposit(roses, (letter_id, 0)), Some((alice, 23)));
+		assert_eq!(Letters::page_deposit(violets, (letter_id, 2)), Some((alice, 26)));
+
+		// the copy bob paid for is still his to pay
+		assert_eq!(Letters::page_deposit(roses, (letter_id, 1)), Some((bob, 23)));
+		assert_eq!(Letters::contributor_deposit(letter_id, bob), 0);
+		assert_eq!(Balances::reserved_balance(bob), 23);
+		assert_eq!(Balances::reserved_balance(alice), 125 + 23 + 26);
+
+		// the bytes the letter keeps on chain are counted by a later migration, each copy of a
+		// shared page is rented by the letter
+		crate::migrations::v4::migrate::<Test>();
+		crate::migrations::v5::migrate::<Test>();
+		assert_eq!(Letters::letter_bytes(letter_id), 13 + 13 + 16);
+	});
+}
+
+#[test]
+fn migrate_live_prints_and_pending_entries_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let master_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::create_edition(Origin::signed(alice), master_id, 3, 0));
+		for _ in 0..3 {
+			assert_ok!(Letters::mint_print(Origin::signed(bob), master_id));
+		}
+		assert_ok!(Letters::burn_letter(Origin::signed(bob), Letters::letter_by_index(2)));
+		assert_ok!(Letters::create_collection(Origin::signed(bob), b"a".to_vec(), vec![]));
+		assert_ok!(Letters::add_to_collection(Origin::signed(bob), 1, master_id));
+
+		// roll back to version 3, which only counted the prints ever minted, and did not count
+		// pending entries
+		let _ = crate::LivePrints::<Test>::remove_all(None);
+		let _ = crate::PendingEntryCount::<Test>::remove_all(None);
+		let _ = crate::LetterPendingEntries::<Test>::remove_all(None);
+		StorageVersion::new(3).put::<Letters>();
+
+		crate::migrations::v4::migrate::<Test>();
+
+		assert_eq!(Letters::on_chain_storage_version(), 4);
+		assert_eq!(Letters::edition_supply(master_id), 3);
+		assert_eq!(Letters::live_prints(master_id), 2);
+		assert_eq!(Letters::pending_entry_count(1), 1);
+		assert!(crate::LetterPendingEntries::<Test>::contains_key(master_id, 1));
+	});
+}
+
+#[test]
+fn migrate_chapter_deposits_works() {
+	build_and_execute(|| {
+		let alice = 1;
+
+		assert_ok!(Letters::create_book(Origin::signed(alice), b"book".to_vec()));
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::add_chapter(Origin::signed(alice), 1, letter_id, b"one".to_vec()));
+		assert_ok!(Letters::create_collection(Origin::signed(alice), b"a".to_vec(), vec![]));
+		assert_ok!(Letters::add_to_collection(Origin::signed(alice), 1, letter_id));
+
+		// roll back to version 4, where chapters did not record the deposit of their title
+		// and letters did not count their collection entries
+		crate::LetterEntryCount::<Test>::remove(letter_id);
+		let (book, reserve) = Letters::book(1).unwrap();
+		let chapters: Vec<_> = book
+			.chapters
+			.iter()
+			.map(|chapter| (chapter.letter_id, chapter.title.clone()))
+			.collect();
+		let old = (book.id, book.owner, book.title, book.price, chapters);
+		let key = crate::Books::<Test>::hashed_key_for(1);
+		frame_support::storage::unhashed::put(&key, &(old, reserve));
+		StorageVersion::new(4).put::<Letters>();
+
+		crate::migrations::v5::migrate::<Test>();
+
+		assert_eq!(Letters::on_chain_storage_version(), 5);
+		let (book, reserve) = Letters::book(1).unwrap();
+		assert_eq!(book.chapters[0].letter_id, letter_id);
+		assert_eq!(book.chapters[0].deposit, 15);
+		assert_eq!(reserve, 50 + 20 + 15);
+		assert_eq!(Letters::letter_entry_count(letter_id), 1);
+
+		assert_ok!(Letters::detach_chapter(Origin::signed(alice), 1, letter_id));
+		assert_eq!(Letters::book(1).unwrap().1, 50 + 20);
+	});
+}
+
+#[test]
+fn deposit_parameters_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::invite_contributor(
+			Origin::signed(alice),
+			letter_id,
+			bob,
+			Role::Writer
+		));
+		assert_ok!(Letters::write_page(Origin::signed(bob), letter_id, b"roses are red".to_vec()));
+		assert_ok!(Letters::write_page(
+			Origin::signed(alice),
+			letter_id,
+			b"violets are blue".to_vec()
+		));
+		assert_eq!(Balances::reserved_balance(alice), 125 + 26);
+		assert_eq!(Balances::reserved_balance(bob), 23);
+
+		let initial = Letters::deposit_parameters();
+		let doubled =
+			DepositParams { letter_base: 100, letter_factor: 10, page_base: 20, page_factor: 2 };
+		assert_ok!(Letters::set_deposit_parameters(Origin::root(), doubled));
+		System::assert_last_event(crate::Event::DepositParametersSet(doubled).into());
+		assert_eq!(Letters::deposit_parameters(), doubled);
+
+		// new letters are charged the new rates, existing ones are left as they are
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		assert_eq!(Balances::reserved_balance(alice), 125 + 26 + 250);
+		assert_eq!(Letters::letter(letter_id).unwrap().1, 125);
+
+		// anyone can bring a letter to the new rates, topping up the owner and whoever stored
+		// its pages
+		assert_ok!(Letters::recompute_deposit(Origin::signed(bob), letter_id));
+		System::assert_last_event(crate::Event::DepositRecomputed(letter_id, 250).into());
+		assert_eq!(Letters::letter(letter_id).unwrap().1, 250);
+		assert_eq!(Balances::reserved_balance(alice), 250 + 52 + 250);
+		assert_eq!(Balances::reserved_balance(bob), 46);
+		let roses = BlakeTwo256::hash(b"roses are red");
+		assert_eq!(Letters::page_deposit(roses, (letter_id, 0)), Some((bob, 46)));
+
+		// and lowering the rates refunds the difference
+		assert_ok!(Letters::set_deposit_parameters(Origin::root(), initial));
+		assert_ok!(Letters::recompute_deposit(Origin::signed(alice), letter_id));
+		assert_eq!(Balances::reserved_balance(alice), 125 + 26 + 250);
+		assert_eq!(Balances::reserved_balance(bob), 23);
+		assert_eq!(Balances::free_balance(bob), BOB_INIT_BALANCE - 23);
+	});
+}
+
+#[test]
+fn deposit_parameters_collection_and_book_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let doubled =
+			DepositParams { letter_base: 100, letter_factor: 10, page_base: 20, page_factor: 2 };
+		assert_ok!(Letters::set_deposit_parameters(Origin::root(), doubled));
+
+		// collections and books are charged the rates of letters
+		assert_ok!(Letters::create_collection(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec()
+		));
+		assert_eq!(Letters::collection(1).unwrap().1, 250);
+		assert_ok!(Letters::create_book(Origin::signed(alice), "bear".as_bytes().to_vec()));
+		assert_eq!(Letters::book(1).unwrap().1, 140);
+		assert_eq!(Balances::reserved_balance(alice), 250 + 140);
+
+		// a chapter title is charged per byte
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"a".as_bytes().to_vec(),
+			"b".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::add_chapter(Origin::signed(alice), 1, letter_id, b"one".to_vec()));
+		assert_eq!(Letters::book(1).unwrap().1, 140 + 30);
+		assert_ok!(Letters::detach_chapter(Origin::signed(alice), 1, letter_id));
+		assert_eq!(Letters::book(1).unwrap().1, 140);
+		assert_eq!(Balances::reserved_balance(alice), 250 + 140 + 120);
+	});
+}
+
+#[test]
+fn deposit_parameters_error() {
+	build_and_execute(|| {
+		let alice = 1;
+		let parameters =
+			DepositParams { letter_base: 2000, letter_factor: 5, page_base: 10, page_factor: 1 };
+
+		assert_noop!(Letters::set_deposit_parameters(Origin::signed(alice), parameters), BadOrigin);
+		assert_noop!(
+			Letters::recompute_deposit(Origin::signed(alice), Letters::letter_id_of(0)),
+			Error::<Test>::NonExistentLetter
+		);
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		// the reserve is left untouched when the holder cannot pay the new rates
+		assert_ok!(Letters::set_deposit_parameters(Origin::root(), parameters));
+		assert_noop!(
+			Letters::recompute_deposit(Origin::signed(alice), letter_id),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(Balances::reserved_balance(alice), 125);
+	});
+}
+
+#[test]
+fn rent_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+		RentPerByte::set(1);
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(bob),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::write_page(Origin::signed(bob), letter_id, b"roses are red".to_vec()));
+		assert_eq!(Balances::free_balance(bob), BOB_INIT_BALANCE - 148);
+
+		// anyone can collect the rent, charged per block for each byte of the pages
+		System::set_block_number(11);
+		assert_ok!(Letters::collect_rent(Origin::signed(alice), letter_id));
+		System::assert_last_event(crate::Event::RentCollected(letter_id, bob, 130).into());
+		assert_eq!(Balances::free_balance(bob), BOB_INIT_BALANCE - 148 - 130);
+		assert_eq!(Letters::rent_paid_until(letter_id), Some(11));
+
+		// it is also collected before a letter is written to
+		System::set_block_number(21);
+		assert_ok!(Letters::write_page(
+			Origin::signed(bob),
+			letter_id,
+			b"violets are blue".to_vec()
+		));
+		assert_eq!(Balances::free_balance(bob), BOB_INIT_BALANCE - 148 - 130 - 130 - 26);
+		assert_eq!(Letters::letter_bytes(letter_id), 13 + 16);
+
+		// and when the chain has weight to spare for a letter of the most pages
+		System::set_block_number(31);
+		assert_eq!(Letters::on_idle(31, 0), 0);
+		assert_eq!(Letters::rent_paid_until(letter_id), Some(21));
+		Letters::on_idle(31, u64::MAX);
+		assert_eq!(Letters::rent_paid_until(letter_id), Some(31));
+		assert_eq!(Balances::free_balance(bob), BOB_INIT_BALANCE - 148 - 130 - 130 - 26 - 290);
+		assert_eq!(Balances::reserved_balance(bob), 148 + 26);
+	});
+}
+
+#[test]
+fn rent_archive_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+		RentPerByte::set(1);
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::write_page(
+			Origin::signed(alice),
+			letter_id,
+			b"roses are red".to_vec()
+		));
+		Balances::make_free_balance_be(&alice, ExistentialDeposit::get());
+
+		// the letter is kept for the grace period
+		System::set_block_number(11);
+		assert_ok!(Letters::collect_rent(Origin::signed(bob), letter_id));
+		assert_eq!(Letters::archived(letter_id), None);
+		assert_eq!(Letters::rent_paid_until(letter_id), Some(1));
+
+		// then its pages are dropped along with their deposit, but the letter deposit stays
+		System::set_block_number(12);
+		assert_ok!(Letters::collect_rent(Origin::signed(bob), letter_id));
+		let content_hash = BlakeTwo256::hash_of(&bounded_pages(vec!["roses are red"]));
+		System::assert_last_event(crate::Event::LetterArchived(letter_id, content_hash).into());
+		assert_eq!(Letters::archived(letter_id), Some(content_hash));
+		assert_noop!(Letters::read_page(letter_id, 0), Error::<Test>::NonExistentPage);
+		assert_eq!(Letters::page_store(BlakeTwo256::hash(b"roses are red")), None);
+		assert_eq!(Letters::letter_bytes(letter_id), 0);
+		assert_eq!(Balances::reserved_balance(alice), 125);
+		assert_eq!(Balances::free_balance(alice), ExistentialDeposit::get() + 23);
+
+		// uploading the same pages brings the letter back
+		Balances::make_free_balance_be(&alice, ALICE_INIT_BALANCE);
+		assert_ok!(Letters::restore_letter(
+			Origin::signed(alice),
+			letter_id,
+			bounded_pages(vec!["roses are red"])
+		));
+		System::assert_last_event(crate::Event::LetterRestored(alice, letter_id).into());
+		assert_eq!(Letters::archived(letter_id), None);
+		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), on_chain(b"roses are red"));
+		assert_eq!(Letters::rent_paid_until(letter_id), Some(12));
+		assert_eq!(Letters::letter_bytes(letter_id), 13);
+		// the page deposit returned by the archive is reserved again
+		assert_eq!(Balances::reserved_balance(alice), 125 + 23);
+	});
+}
+
+#[test]
+fn rent_error() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+		RentPerByte::set(1);
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+		assert_ok!(Letters::write_page(
+			Origin::signed(alice),
+			letter_id,
+			b"roses are red".to_vec()
+		));
+		assert_noop!(
+			Letters::restore_letter(
+				Origin::signed(alice),
+				letter_id,
+				bounded_pages(vec!["roses are red"])
+			),
+			Error::<Test>::NotArchived
+		);
+
+		// a letter cannot be written to or transferred while its rent is unpaid
+		Balances::make_free_balance_be(&alice, ExistentialDeposit::get());
+		System::set_block_number(11);
+		assert_noop!(
+			Letters::write_page(Origin::signed(alice), letter_id, b"roses are red".to_vec()),
+			Error::<Test>::RentUnpaid
+		);
+		assert_noop!(
+			Letters::transfer(Origin::signed(alice), bob, letter_id),
+			Error::<Test>::RentUnpaid
+		);
+
+		System::set_block_number(12);
+		assert_ok!(Letters::collect_rent(Origin::signed(bob), letter_id));
+		assert_noop!(
+			Letters::write_page(Origin::signed(alice), letter_id, b"roses are red".to_vec()),
+			Error::<Test>::LetterArchived
+		);
+		assert_noop!(
+			Letters::recompute_deposit(Origin::signed(alice), letter_id),
+			Error::<Test>::LetterArchived
+		);
+		assert_noop!(
+			Letters::restore_letter(
+				Origin::signed(bob),
+				letter_id,
+				bounded_pages(vec!["roses are red"])
+			),
+			Error::<Test>::LetterNotOwned
+		);
+		assert_noop!(
+			Letters::restore_letter(
+				Origin::signed(alice),
+				letter_id,
+				bounded_pages(vec!["roses are blue"])
+			),
+			Error::<Test>::ContentMismatch
+		);
+	});
+}
+
+fn content_id(page: &[u8]) -> ContentId {
+	ContentId { codec: 0x55, digest: sp_core::hashing::blake2_256(page), len: page.len() as u32 }
+}
+
+#[test]
+fn offchain_page_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let poem = b"a poem far too long to be kept on chain";
+		let reason = BlakeTwo256::hash(b"illegal");
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		// only the content identifier is charged for, whatever the length of the page
+		assert_ok!(Letters::write_offchain_page(
+			Origin::signed(alice),
+			letter_id,
+			content_id(poem)
+		));
+		System::assert_last_event(crate::Event::OffchainPageWritten(alice, letter_id, 0).into());
+		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), Page::OffChain(content_id(poem)));
+		assert_eq!(Balances::reserved_balance(alice), 125 + 54);
+		assert_eq!(Letters::verify_page(letter_id, 0, poem), Ok(true));
+		assert_eq!(Letters::verify_page(letter_id, 0, b"a poem"), Ok(false));
+
+		// appending starts a new page rather than extending the off-chain one
+		assert_ok!(Letters::append_to_page(Origin::signed(alice), letter_id, b"abc".to_vec()));
+		System::assert_last_event(crate::Event::PageAppended(alice, letter_id, 1, 1).into());
+		assert_eq!(Letters::read_page(letter_id, 1).unwrap(), on_chain(b"abc"));
+		assert_eq!(Letters::verify_page(letter_id, 1, b"abc"), Ok(true));
+		assert_eq!(Letters::verify_page(letter_id, 1, b"abd"), Ok(false));
+
+		// redacting the page drops its content identifier and releases its deposit
+		assert_ok!(Letters::force_redact_page(
+			Origin::root(),
+			letter_id,
+			0,
+			reason,
+			DepositAction::Refund,
+			1
+		));
+		assert_eq!(Letters::offchain_page(letter_id, 0), None);
+		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), Page::Redacted);
+		assert_eq!(Balances::reserved_balance(alice), 125 + 13);
+	});
+}
+
+#[test]
+fn offchain_page_error() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+		let poem = b"a poem far too long to be kept on chain";
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		assert_noop!(
+			Letters::write_offchain_page(Origin::signed(bob), letter_id, content_id(poem)),
+			Error::<Test>::LetterNotOwned
+		);
+		assert_noop!(Letters::verify_page(letter_id, 0, poem), Error::<Test>::NonExistentPage);
+		assert_noop!(
+			Letters::verify_page(Letters::letter_id_of(1), 0, poem),
+			Error::<Test>::NonExistentLetter
+		);
+
+		assert_ok!(Letters::seal_letter(Origin::signed(alice), letter_id));
+		assert_noop!(
+			Letters::write_offchain_page(Origin::signed(alice), letter_id, content_id(poem)),
+			Error::<Test>::LetterSealed
+		);
+	});
+}
+
+// Test externalities reading the offchain database that indexed pages are written to.
+fn offchain_test_ext() -> sp_io::TestExternalities {
+	let mut ext = new_test_ext();
+	let (offchain, _) = TestOffchainExt::with_offchain_db(ext.offchain_db());
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext
+}
+
+#[test]
+fn indexed_page_works() {
+	let alice = 1;
+	let poem = b"a poem kept by the nodes indexing it";
+	let reason = BlakeTwo256::hash(b"illegal");
+
+	let mut ext = offchain_test_ext();
+	let letter_id = ext.execute_with(|| {
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		// only the hash of the page is kept on chain
+		assert_ok!(Letters::write_indexed_page(Origin::signed(alice), letter_id, poem.to_vec()));
+		System::assert_last_event(crate::Event::PageIndexed(alice, letter_id, 0).into());
+		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), Page::OffChain(content_id(poem)));
+		assert_eq!(Balances::reserved_balance(alice), 125 + 54);
+		letter_id
+	});
+
+	// the bytes are in the offchain database once the block is imported
+	ext.persist_offchain_overlay();
+	ext.execute_with(|| {
+		let page = Letters::indexed_page(letter_id, 0).unwrap();
+		assert_eq!(page, poem.to_vec());
+		assert_eq!(Letters::verify_page(letter_id, 0, &page), Ok(true));
+
+		// and are cleared from it when the page is redacted
+		assert_ok!(Letters::force_redact_page(
+			Origin::root(),
+			letter_id,
+			0,
+			reason,
+			DepositAction::Refund,
+			1
+		));
+		Letters::do_try_state().unwrap();
+	});
+	ext.persist_offchain_overlay();
+	ext.execute_with(|| assert_eq!(Letters::indexed_page(letter_id, 0), None));
+}
+
+#[test]
+fn indexed_page_error() {
+	offchain_test_ext().execute_with(|| {
+		let alice = 1;
+		let bob = 2;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		assert_noop!(
+			Letters::write_indexed_page(
+				Origin::signed(alice),
+				letter_id,
+				vec![b'a'; MaxPageLength::get() as usize + 1]
+			),
+			Error::<Test>::PageLenOverflow
+		);
+		assert_noop!(
+			Letters::write_indexed_page(Origin::signed(bob), letter_id, b"roses are red".to_vec()),
+			Error::<Test>::LetterNotOwned
+		);
+		assert_eq!(Letters::indexed_page(letter_id, 0), None);
+	});
+}
+
+#[test]
+fn compressed_page_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let song = b"la ".repeat(100);
+		let compressed = lz4_flex::block::compress(&song);
+		let reason = BlakeTwo256::hash(b"illegal");
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		// the deposit is charged on the compressed bytes, but the page reads decompressed
+		assert_ok!(Letters::write_compressed_page(
+			Origin::signed(alice),
+			letter_id,
+			compressed.clone(),
+			Compression::Lz4
+		));
+		System::assert_last_event(
+			crate::Event::CompressedPageWritten(alice, letter_id, 0, Compression::Lz4).into(),
+		);
+		assert_eq!(Letters::compressed_page(letter_id, 0), Some(Compression::Lz4));
+		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), on_chain(&song));
+		assert_eq!(Letters::verify_page(letter_id, 0, &song), Ok(true));
+		assert_eq!(Letters::verify_page(letter_id, 0, &compressed), Ok(false));
+		assert_eq!(Balances::reserved_balance(alice), 125 + 10 + compressed.len() as u128);
+
+		// appending starts a new page rather than extending the compressed one
+		assert_ok!(Letters::append_to_page(Origin::signed(alice), letter_id, b"abc".to_vec()));
+		System::assert_last_event(crate::Event::PageAppended(alice, letter_id, 1, 1).into());
+		assert_eq!(Letters::read_page(letter_id, 1).unwrap(), on_chain(b"abc"));
+
+		// redacting the page leaves an empty uncompressed page in its place
+		assert_ok!(Letters::force_redact_page(
+			Origin::root(),
+			letter_id,
+			0,
+			reason,
+			DepositAction::Refund,
+			1
+		));
+		assert_eq!(Letters::compressed_page(letter_id, 0), None);
+		assert_eq!(Letters::read_page(letter_id, 0).unwrap(), Page::Redacted);
+		assert_eq!(Balances::reserved_balance(alice), 125 + 13);
+	});
+}
+
+#[test]
+fn compressed_page_error() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+		let compressed = lz4_flex::block::compress(&b"la ".repeat(100));
+		let max_len = MaxPageLength::get() as usize;
+
+		assert_ok!(Letters::init_letter(
+			Origin::signed(alice),
+			"hello world".as_bytes().to_vec(),
+			"bear".as_bytes().to_vec(),
+			TransferPolicy::Transferable
+		));
+		let letter_id = Letters::letter_by_index(1);
+
+		assert_noop!(
+			Letters::write_compressed_page(
+				Origin::signed(bob),
+				letter_id,
+				compressed.clone(),
+				Compression::Lz4
+			),
+			Error::<Test>::LetterNotOwned
+		);
+		assert_noop!(
+			Letters::write_compressed_page(
+				Origin::signed(alice),
+				letter_id,
+				vec![0xff; 4],
+				Compression::Lz4
+			),
+			Error::<Test>::InvalidCompression
+		);
+		// a few compressed bytes may not decompress past the longest page
+		assert_noop!(
+			Letters::write_compressed_page(
+				Origin::signed(alice),
+				letter_id,
+				lz4_flex::block::compress(&vec![0; max_len + 1]),
+				Compression::Lz4
+			),
+			Error::<Test>::InvalidCompression
+		);
+		assert_noop!(
+			Letters::write_compressed_page(
+				Origin::signed(alice),
+				letter_id,
+				vec![0; max_len + 1],
+				Compression::Lz4
+			),
+			Error::<Test>::PageLenOverflow
+		);
+
+		assert_ok!(Letters::seal_letter(Origin::signed(alice), letter_id));
+		assert_noop!(
+			Letters::write_compressed_page(
+				Origin::signed(alice),
+				letter_id,
+				compressed,
+				Compression::Lz4
+			),
+			Error::<Test>::LetterSealed
+		);
+	});
+}
+
+#[test]
+fn page_store_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+		let page_hash = BlakeTwo256::hash(b"roses are red");
+
+		for who in [alice, bob] {
+			assert_ok!(Letters::init_letter(
+				Origin::signed(who),
+				"hello world".as_bytes().to_vec(),
+				"bear".as_bytes().to_vec(),
+				TransferPolicy::Transferable
+			));
+		}
+		let first_id = Letters::letter_by_index(1);
+		let second_id = Letters::letter_by_index(2);
+
+		// the bytes are stored once, and every page referring to them pays its own deposit
+		assert_ok!(Letters::write_page(Origin::signed(alice), first_id, b"roses are red".to_vec()));
+		assert_ok!(Letters::write_page(Origin::signed(bob), second_id, b"roses are red".to_vec()));
+		assert_eq!(Letters::page_store(page_hash).unwrap().refs, 2);
+		assert_eq!(Letters::page_deposit(page_hash, (first_id, 0)), Some((alice, 23)));
+		assert_eq!(Letters::page_deposit(page_hash, (second_id, 0)), Some((bob, 23)));
+		assert_eq!(Letters::read_page(second_id, 0).unwrap(), on_chain(b"roses are red"));
+		assert_eq!(Balances::reserved_balance(alice), 125 + 23);
+		assert_eq!(Balances::reserved_balance(bob), 125 + 23);
+
+		// the bytes outlive the first letter, whose writer gets their deposit back
+		assert_ok!(Letters::burn_letter(Origin::signed(alice), first_id));
+		assert_eq!(Letters::page_store(page_hash).unwrap().refs, 1);
+		assert_eq!(Letters::page_deposit(page_hash, (first_id, 0)), None);
+		assert_eq!(Letters::read_page(second_id, 0).unwrap(), on_chain(b"roses are red"));
+		assert_eq!(Balances::reserved_balance(alice), 0);
+
+		// and go with the last page referring to them
+		assert_ok!(Letters::burn_letter(Origin::signed(bob), second_id));
+		assert_eq!(Letters::page_store(page_hash), None);
+		assert_eq!(Balances::reserved_balance(bob), 0);
+	});
+}
+
+#[test]
+fn page_store_shared_works() {
+	build_and_execute(|| {
+		let alice = 1;
+		let bob = 2;
+		let reason = BlakeTwo256::hash(b"illegal");
+		let page_hash = BlakeTwo256::hash(b"roses are red");
+
+		for who in [alice, bob] {
+			assert_ok!(Letters::init_letter(
+				Origin::signed(who),
+				"hello world".as_bytes().to_vec(),
+				"bear".as_bytes().to_vec(),
+				TransferPolicy::Transferable
+			));
+		}
+		let first_id = Letters::letter_by_index(1);
+		let second_id = Letters::letter_by_index(2);
+		assert_ok!(Letters::write_page(Origin::signed(alice), first_id, b"roses are red".to_vec()));
+		assert_ok!(Letters::write_page(Origin::signed(bob), second_id, b"roses are red".to_vec()));
+
+		// appending to a shared page stores new bytes for this letter only
+		assert_ok!(Letters::append_to_page(Origin::signed(bob), second_id, b"!".to_vec()));
+		assert_eq!(Letters::read_page(first_id, 0).unwrap(), on_chain(b"roses are red"));
+		assert_eq!(Letters::read_page(second_id, 0).unwrap(), on_chain(b"roses are red!"));
+		assert_eq!(Letters::page_store(page_hash).unwrap().refs, 1);
+		assert_eq!(Balances::reserved_balance(bob), 125 + 24);
+
+		// writing the bytes again shares them once more, at the cost of another deposit
+		assert_ok!(Letters::write_page(Origin::signed(bob), second_id, b"roses are red".to_vec()));
+		assert_eq!(Letters::page_store(page_hash).unwrap().refs, 2);
+		assert_eq!(Balances::reserved_balance(bob), 125 + 24 + 23);
+
+		// the witness has to cover every page sharing the bytes
+		assert_noop!(
+			Letters::force_redact_page(
+				Origin::root(),
+				first_id,
+				0,
+				reason,
+				DepositAction::Slash,
+				1
+			),
+			Error::<Test>::BadWitness
+		);
+
+		// redacting one copy redacts them all, slashing the deposit of each
+		let issuance = Balances::total_issuance();
+		assert_ok!(Letters::force_redact_page(
+			Origin::root(),
+			first_id,
+			0,
+			reason,
+			DepositAction::Slash,
+			2
+		));
+		for (letter_id, index) in [(first_id, 0), (second_id, 1)] {
+			System::assert_has_event(
+				crate::Event::PageRedacted(letter_id, index, reason, 23, DepositAction::Slash)
+					.into(),
+			);
+			assert_eq!(Letters::read_page(letter_id, index).unwrap(), Page::Redacted);
+		}
+		assert_eq!(Letters::read_page(second_id, 0).unwrap(), on_chain(b"roses are red!"));
+		assert_eq!(Letters::page_store(page_hash), None);
+		assert_eq!(Balances::reserved_balance(alice), 125);
+		assert_eq!(Balances::reserved_balance(bob), 125 + 24);
+		assert_eq!(Balances::total_issuance(), issuance - 2 * 23);
+	});
+}
+
+#[derive(Clone, Debug)]
+enum DepositOp {
+	Init(u64),
+	WritePage(u64, usize, usize),
+	AppendToPage(u64, usize, usize),
+	Invite(usize, u64),
+	Transfer(usize, u64),
+	Burn(usize),
+	SetDeposit(usize, u128),
+	Redact(usize, u32, bool),
+}
+
+fn deposit_op() -> impl Strategy<Value = DepositOp> {
+	let who = 1u64..=3;
+	let len = 0usize..40;
+	prop_oneof![
+		who.clone().prop_map(DepositOp::Init),
+		(who.clone(), any::<usize>(), len.clone())
+			.prop_map(|(who, letter, len)| DepositOp::WritePage(who, letter, len)),
+		(who.clone(), any::<usize>(), len)
+			.prop_map(|(who, letter, len)| DepositOp::AppendToPage(who, letter, len)),
+		(any::<usize>(), who.clone()).prop_map(|(letter, who)| DepositOp::Invite(letter, who)),
+		(any::<usize>(), who).prop_map(|(letter, to)| DepositOp::Transfer(letter, to)),
+		any::<usize>().prop_map(DepositOp::Burn),
+		(any::<usize>(), 0u128..300)
+			.prop_map(|(letter, deposit)| DepositOp::SetDeposit(letter, deposit)),
+		(any::<usize>(), 0u32..4, any::<bool>())
+			.prop_map(|(letter, index, slash)| DepositOp::Redact(letter, index, slash)),
+	]
+}
+
+// Applies an operation to one of the existing letters, ignoring whether it fails.
+fn apply_deposit_op(op: DepositOp) {
+	let count = Letters::all_letters_count();
+	let pick = |letter: usize| Letters::letter_by_index(1 + letter as u64 % count.max(1));
+
+	match op {
+		DepositOp::Init(who) => {
+			let _ = Letters::init_letter(
+				Origin::signed(who),
+				"hello world".as_bytes().to_vec(),
+				"bear".as_bytes().to_vec(),
+				TransferPolicy::Transferable,
+			);
+		},
+		_ if count == 0 => (),
+		DepositOp::WritePage(who, letter, len) => {
+			let _ = Letters::write_page(Origin::signed(who), pick(letter), vec![b'a'; len]);
+		},
+		DepositOp::AppendToPage(who, letter, len) => {
+			let _ = Letters::append_to_page(Origin::signed(who), pick(letter), vec![b'a'; len]);
+		},
+		DepositOp::Invite(letter, who) => {
+			let letter_id = pick(letter);
+			let owner = Letters::owner_of(letter_id).unwrap();
+			let _ =
+				Letters::invite_contributor(Origin::signed(owner), letter_id, who, Role::Writer);
+		},
+		DepositOp::Transfer(letter, to) => {
+			let letter_id = pick(letter);
+			let owner = Letters::owner_of(letter_id).unwrap();
+			let _ = Letters::transfer(Origin::signed(owner), to, letter_id);
+		},
+		DepositOp::Burn(letter) => {
+			let letter_id = pick(letter);
+			let owner = Letters::owner_of(letter_id).unwrap();
+			let _ = Letters::burn_letter(Origin::signed(owner), letter_id);
+		},
+		DepositOp::SetDeposit(letter, deposit) => {
+			let _ = Letters::force_set_deposit(Origin::root(), pick(letter), deposit);
+		},
+		DepositOp::Redact(letter, index, slash) => {
+			let action = if slash { DepositAction::Slash } else { DepositAction::Refund };
+			let reason = BlakeTwo256::hash(b"spam");
+			let letter_id = pick(letter);
+			let witness = Letters::letter(letter_id)
+				.and_then(|(letter, _)| letter.pages.get(index as usize).copied().flatten())
+				.and_then(Letters::page_store)
+				.map_or(1, |stored| stored.refs);
+			let _ = Letters::force_redact_page(
+				Origin::root(),
+				letter_id,
+				index,
+				reason,
+				action,
+				witness,
+			);
+		},
+	}
+}
+
+proptest! {
+	#[test]
+	fn deposits_match_reserved_balance(ops in prop::collection::vec(deposit_op(), 1..60)) {
+		build_and_execute(|| {
+			for op in ops {
+				apply_deposit_op(op);
+
+				let held = crate::deposit::held::<Test>();
+				for who in 1..=3 {
+					assert_eq!(
+						Balances::reserved_balance(who),
+						held.get(&who).copied().unwrap_or_default()
+					);
+				}
+			}
+		});
+	}
+}
@@ -1,11 +1,11 @@
 
-//! Autogenerated weights for `pallet_letters`
+//! Weights for `pallet_letters`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2022-07-27, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! These are hand estimates, not the output of a benchmark run. Each one counts the storage the
+//! call touches, listed above it, and scales with the components of its benchmark in
+//! `benchmarking.rs`. Regenerate them on reference hardware before relying on them in a runtime.
 
-// Executed Command:
+// Command to regenerate:
 // ./target/release/node-template
 // benchmark
 // pallet
@@ -37,9 +37,50 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn init_letter(t: u32, _a: u32, ) -> Weight;
 	fn write_page(p: u32, ) -> Weight;
+	fn write_pages(n: u32, b: u32, ) -> Weight;
+	fn append_to_page(b: u32, ) -> Weight;
+	fn write_offchain_page() -> Weight;
+	fn write_indexed_page(p: u32, ) -> Weight;
+	fn write_compressed_page(c: u32, ) -> Weight;
+	fn decompress_page(d: u32, ) -> Weight;
 	fn set_price() -> Weight;
 	fn transfer() -> Weight;
-	fn buy_letter() -> Weight;
+	fn buy_letter(s: u32, ) -> Weight;
+	fn create_edition() -> Weight;
+	fn mint_print() -> Weight;
+	fn create_collection(t: u32, _d: u32, ) -> Weight;
+	fn add_to_collection() -> Weight;
+	fn remove_from_collection() -> Weight;
+	fn move_in_collection() -> Weight;
+	fn destroy_collection(e: u32, p: u32, ) -> Weight;
+	fn create_book(t: u32, ) -> Weight;
+	fn add_chapter(t: u32, ) -> Weight;
+	fn detach_chapter() -> Weight;
+	fn set_book_price() -> Weight;
+	fn transfer_book(c: u32, ) -> Weight;
+	fn buy_book(c: u32, ) -> Weight;
+	fn destroy_book(c: u32, ) -> Weight;
+	fn invite_contributor() -> Weight;
+	fn revoke_contributor() -> Weight;
+	fn seal_letter() -> Weight;
+	fn set_revenue_split(s: u32, ) -> Weight;
+	fn fractionalize() -> Weight;
+	fn redeem() -> Weight;
+	fn burn_letter(p: u32, e: u32, ) -> Weight;
+	fn freeze() -> Weight;
+	fn thaw() -> Weight;
+	fn force_redact_page(r: u32, ) -> Weight;
+	fn force_burn_letter(p: u32, e: u32, ) -> Weight;
+	fn report_letter() -> Weight;
+	fn resolve_report() -> Weight;
+	fn force_transfer() -> Weight;
+	fn force_set_price() -> Weight;
+	fn force_set_deposit() -> Weight;
+	fn force_rebuild_owner_index(n: u32, ) -> Weight;
+	fn set_deposit_parameters() -> Weight;
+	fn recompute_deposit(p: u32, ) -> Weight;
+	fn collect_rent(p: u32, ) -> Weight;
+	fn restore_letter(n: u32, b: u32, ) -> Weight;
 }
 
 /// Weight functions for `pallet_letters`.
@@ -51,22 +92,147 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Letters AllLettersCount (r:1 w:1)
 	// Storage: Letters Letters (r:0 w:1)
 	// Storage: Letters AllLettersArray (r:0 w:1)
-	// Storage: Letters OwnedLettersArray (r:0 w:1)
-	// Storage: Letters OwnedLettersIndex (r:0 w:1)
+	// Storage: Letters OwnedLetters (r:0 w:1)
 	// Storage: Letters AllLettersIndex (r:0 w:1)
+	// Storage: Letters DepositHolder (r:0 w:1)
+	// Storage: Letters RentPaidUntil (r:0 w:1)
 	fn init_letter(_t: u32, _a: u32, ) -> Weight {
 		(68_547_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
 	}
 	// Storage: Letters Letters (r:1 w:1)
 	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters PageWriter (r:0 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:0 w:1)
 	fn write_page(p: u32, ) -> Weight {
-		(60_317_000 as Weight)
+		(64_871_000 as Weight)
 			// Standard Error: 5_000
 			.saturating_add((1_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Frozen (r:1 w:0)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters PageWriter (r:0 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:0 w:1)
+	fn write_pages(n: u32, b: u32, ) -> Weight {
+		(71_305_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((3_514_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 0
+			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Frozen (r:1 w:0)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:0)
+	// Storage: Letters PageWriter (r:1 w:1)
+	// Storage: Letters RedactedPages (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters OffchainPages (r:1 w:0)
+	// Storage: Letters CompressedPages (r:1 w:0)
+	// Storage: Letters PageStore (r:2 w:2)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:1 w:2)
+	fn append_to_page(b: u32, ) -> Weight {
+		(70_118_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Frozen (r:1 w:0)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters PageWriter (r:0 w:1)
+	// Storage: Letters OffchainPages (r:0 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn write_offchain_page() -> Weight {
+		(61_937_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Frozen (r:1 w:0)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters PageWriter (r:0 w:1)
+	// Storage: Letters OffchainPages (r:0 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn write_indexed_page(p: u32, ) -> Weight {
+		(63_104_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((3_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Frozen (r:1 w:0)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters PageWriter (r:0 w:1)
+	// Storage: Letters CompressedPages (r:0 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:0 w:1)
+	fn write_compressed_page(c: u32, ) -> Weight {
+		(66_513_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((1_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn decompress_page(d: u32, ) -> Weight {
+		(3_412_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(d as Weight))
 	}
 	// Storage: Letters Letters (r:1 w:1)
 	// Storage: Letters LetterOwner (r:1 w:0)
@@ -76,27 +242,541 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Letters LetterOwner (r:1 w:1)
-	// Storage: Letters Letters (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:1)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Letters OwnedLettersCount (r:2 w:2)
-	// Storage: Letters OwnedLettersIndex (r:1 w:2)
-	// Storage: Letters OwnedLettersArray (r:1 w:3)
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
 	fn transfer() -> Weight {
-		(87_866_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(8 as Weight))
-			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+		(71_204_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters ChapterOf (r:1 w:0)
+	// Storage: Letters RevenueSplits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Letters OwnedLettersCount (r:2 w:2)
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn buy_letter(s: u32, ) -> Weight {
+		(104_912_000 as Weight)
+			// Standard Error: 14_000
+			.saturating_add((27_518_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Storage: Letters Letters (r:1 w:0)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Prints (r:1 w:0)
+	// Storage: Letters Editions (r:1 w:1)
+	fn create_edition() -> Weight {
+		(31_204_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters Editions (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:2)
+	// Storage: Letters LetterOwner (r:2 w:1)
+	// Storage: Letters EditionSupply (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Letters OwnedLettersCount (r:1 w:1)
+	// Storage: Letters AllLettersCount (r:1 w:1)
+	// Storage: Letters Nonce (r:1 w:1)
+	// Storage: Letters Prints (r:0 w:1)
+	// Storage: Letters AllLettersArray (r:0 w:1)
+	// Storage: Letters OwnedLetters (r:0 w:1)
+	// Storage: Letters AllLettersIndex (r:0 w:1)
+	// Storage: Letters DepositHolder (r:0 w:1)
+	// Storage: Letters RentPaidUntil (r:0 w:1)
+	// Storage: Letters LivePrints (r:1 w:1)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	fn mint_print() -> Weight {
+		(106_912_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+	}
+	// Storage: Letters CollectionsCount (r:1 w:1)
+	// Storage: Letters Collections (r:0 w:1)
+	// Storage: Letters DepositParameters (r:1 w:0)
+	fn create_collection(t: u32, _d: u32, ) -> Weight {
+		(41_825_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((2_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Letters Collections (r:1 w:0)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters LetterCollections (r:1 w:1)
+	// Storage: Letters PendingEntries (r:1 w:1)
+	// Storage: Letters CollectionEntries (r:1 w:1)
+	// Storage: Letters PendingEntryCount (r:1 w:1)
+	// Storage: Letters LetterPendingEntries (r:0 w:1)
+	// Storage: Letters LetterEntryCount (r:1 w:1)
+	fn add_to_collection() -> Weight {
+		(38_412_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Letters Collections (r:1 w:0)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters PendingEntries (r:1 w:1)
+	// Storage: Letters PendingEntryCount (r:1 w:1)
+	// Storage: Letters LetterPendingEntries (r:0 w:1)
+	// Storage: Letters LetterCollections (r:1 w:1)
+	// Storage: Letters CollectionEntries (r:1 w:1)
+	// Storage: Letters LetterEntryCount (r:1 w:1)
+	fn remove_from_collection() -> Weight {
+		(35_967_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Letters Collections (r:1 w:0)
+	// Storage: Letters CollectionEntries (r:1 w:1)
+	fn move_in_collection() -> Weight {
+		(27_130_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters Collections (r:1 w:1)
+	// Storage: Letters CollectionEntries (r:1 w:1)
+	// Storage: Letters PendingEntries (r:1 w:1)
+	// Storage: Letters PendingEntryCount (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters LetterCollections (r:0 w:1)
+	// Storage: Letters LetterPendingEntries (r:0 w:1)
+	// Storage: Letters LetterEntryCount (r:1 w:1)
+	fn destroy_collection(e: u32, p: u32, ) -> Weight {
+		(36_284_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((2_147_000 as Weight).saturating_mul(e as Weight))
+			// Standard Error: 16_000
+			.saturating_add((6_902_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: Letters BooksCount (r:1 w:1)
+	// Storage: Letters Books (r:0 w:1)
+	// Storage: Letters DepositParameters (r:1 w:0)
+	fn create_book(t: u32, ) -> Weight {
+		(39_561_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((2_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Letters Books (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters ChapterOf (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:0)
+	// Storage: Letters Frozen (r:1 w:0)
+	// Storage: Letters Hidden (r:1 w:0)
+	// Storage: Letters DepositParameters (r:1 w:0)
+	fn add_chapter(t: u32, ) -> Weight {
+		(42_307_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((2_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Letters Books (r:1 w:1)
+	// Storage: Letters ChapterOf (r:1 w:1)
+	fn detach_chapter() -> Weight {
+		(37_884_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Letters Books (r:1 w:1)
+	fn set_book_price() -> Weight {
+		(24_915_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters Books (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Letters OwnedLettersCount (r:2 w:2)
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn transfer_book(c: u32, ) -> Weight {
+		(45_102_000 as Weight)
+			// Standard Error: 31_000
+			.saturating_add((84_716_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((8 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((10 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: Letters Books (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Letters OwnedLettersCount (r:2 w:2)
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters Hidden (r:1 w:0)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn buy_book(c: u32, ) -> Weight {
+		(72_648_000 as Weight)
+			// Standard Error: 31_000
+			.saturating_add((84_716_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((10 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: Letters Books (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters ChapterOf (r:0 w:1)
+	fn destroy_book(c: u32, ) -> Weight {
+		(31_606_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((2_173_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:1)
+	fn invite_contributor() -> Weight {
+		(30_482_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters Contributors (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	fn revoke_contributor() -> Weight {
+		(29_736_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:1)
+	fn seal_letter() -> Weight {
+		(28_964_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:0)
+	// Storage: Letters RevenueSplits (r:0 w:1)
+	fn set_revenue_split(s: u32, ) -> Weight {
+		(27_203_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((412_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters ChapterOf (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:0)
+	// Storage: Letters Fractionalized (r:1 w:1)
+	// Storage: Letters OwnedLettersCount (r:2 w:2)
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters FractionNonce (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn fractionalize() -> Weight {
+		(118_452_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	// Storage: Letters Fractionalized (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters OwnedLettersCount (r:2 w:2)
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn redeem() -> Weight {
+		(121_093_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(16 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters ChapterOf (r:1 w:0)
+	// Storage: Letters LivePrints (r:2 w:1)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters OwnedLettersCount (r:1 w:1)
+	// Storage: Letters OwnedLetters (r:0 w:1)
+	// Storage: Letters AllLettersCount (r:1 w:1)
+	// Storage: Letters AllLettersIndex (r:1 w:2)
+	// Storage: Letters AllLettersArray (r:1 w:2)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters LetterCollections (r:1 w:1)
+	// Storage: Letters LetterPendingEntries (r:1 w:1)
+	// Storage: Letters PendingEntries (r:1 w:1)
+	// Storage: Letters PendingEntryCount (r:1 w:1)
+	// Storage: Letters PageWriter (r:1 w:1)
+	// Storage: Letters RedactedPages (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters RevenueSplits (r:0 w:1)
+	// Storage: Letters Editions (r:0 w:1)
+	// Storage: Letters Prints (r:1 w:1)
+	// Storage: Letters EditionSupply (r:0 w:1)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:0 w:1)
+	// Storage: Letters Archived (r:0 w:1)
+	// Storage: Letters OffchainPages (r:1 w:1)
+	// Storage: Letters CompressedPages (r:1 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LetterEntryCount (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:1 w:1)
+	fn burn_letter(p: u32, e: u32, ) -> Weight {
+		(74_310_000 as Weight)
+			// Standard Error: 9_000
+			.saturating_add((12_406_000 as Weight).saturating_mul(p as Weight))
+			// Standard Error: 21_000
+			.saturating_add((9_118_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().writes(21 as Weight))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(e as Weight)))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Frozen (r:1 w:1)
+	fn freeze() -> Weight {
+		(21_873_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Frozen (r:1 w:1)
+	fn thaw() -> Weight {
+		(22_410_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Letters LetterOwner (r:1 w:0)
 	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters RedactedPages (r:1 w:1)
+	// Storage: Letters PageWriter (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters OffchainPages (r:1 w:1)
+	// Storage: Letters CompressedPages (r:0 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters PageDeposits (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	fn force_redact_page(r: u32, ) -> Weight {
+		(9_214_000 as Weight)
+			// Standard Error: 12_000
+			.saturating_add((36_481_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((11 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((8 as Weight).saturating_mul(r as Weight)))
+	}
 	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters ChapterOf (r:1 w:1)
+	// Storage: Letters Books (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Fractionalized (r:1 w:1)
+	// Storage: Letters OwnedLettersCount (r:1 w:1)
+	// Storage: Letters OwnedLetters (r:0 w:1)
+	// Storage: Letters AllLettersCount (r:1 w:1)
+	// Storage: Letters AllLettersIndex (r:1 w:2)
+	// Storage: Letters AllLettersArray (r:1 w:2)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters LetterCollections (r:1 w:1)
+	// Storage: Letters LetterPendingEntries (r:1 w:1)
+	// Storage: Letters PendingEntries (r:1 w:1)
+	// Storage: Letters PendingEntryCount (r:1 w:1)
+	// Storage: Letters PageWriter (r:1 w:1)
+	// Storage: Letters RedactedPages (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters RevenueSplits (r:0 w:1)
+	// Storage: Letters Editions (r:0 w:1)
+	// Storage: Letters EditionSupply (r:0 w:1)
+	// Storage: Letters Prints (r:1 w:1)
+	// Storage: Letters Frozen (r:0 w:1)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:0 w:1)
+	// Storage: Letters Archived (r:0 w:1)
+	// Storage: Letters OffchainPages (r:1 w:1)
+	// Storage: Letters CompressedPages (r:1 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LivePrints (r:1 w:1)
+	// Storage: Letters LetterEntryCount (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:1 w:1)
+	fn force_burn_letter(p: u32, e: u32, ) -> Weight {
+		(96_524_000 as Weight)
+			// Standard Error: 9_000
+			.saturating_add((12_517_000 as Weight).saturating_mul(p as Weight))
+			// Standard Error: 21_000
+			.saturating_add((9_204_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().writes(27 as Weight))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(e as Weight)))
+	}
+	// Storage: Letters Letters (r:1 w:0)
+	// Storage: Letters Reports (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters Hidden (r:1 w:1)
+	fn report_letter() -> Weight {
+		(38_615_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Letters Reports (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters Hidden (r:1 w:1)
+	fn resolve_report() -> Weight {
+		(36_087_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Fractionalized (r:2 w:0)
+	// Storage: Letters ChapterOf (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Letters OwnedLettersCount (r:2 w:2)
-	// Storage: Letters OwnedLettersIndex (r:1 w:2)
-	// Storage: Letters OwnedLettersArray (r:1 w:3)
-	fn buy_letter() -> Weight {
-		(114_926_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	fn force_transfer() -> Weight {
+		(58_937_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
 			.saturating_add(T::DbWeight::get().writes(11 as Weight))
 	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:1)
+	fn force_set_price() -> Weight {
+		(23_516_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	fn force_set_deposit() -> Weight {
+		(32_779_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Letters AllLettersCount (r:1 w:0)
+	// Storage: Letters OwnedLettersCount (r:1 w:1)
+	// Storage: Letters AllLettersArray (r:1 w:0)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters OwnedLetters (r:0 w:1)
+	fn force_rebuild_owner_index(n: u32, ) -> Weight {
+		(14_210_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((9_847_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Letters DepositParameters (r:0 w:1)
+	fn set_deposit_parameters() -> Weight {
+		(14_102_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters DepositParameters (r:1 w:0)
+	// Storage: Letters RedactedPages (r:1 w:0)
+	// Storage: Letters PageWriter (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:2 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters OffchainPages (r:1 w:0)
+	// Storage: Letters PageStore (r:1 w:0)
+	// Storage: Letters PageDeposits (r:1 w:1)
+	fn recompute_deposit(p: u32, ) -> Weight {
+		(48_233_000 as Weight)
+			// Standard Error: 6_000
+			.saturating_add((9_871_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((7 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters Archived (r:0 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:1 w:1)
+	fn collect_rent(p: u32, ) -> Weight {
+		(38_316_000 as Weight)
+			// Standard Error: 41_000
+			.saturating_add((18_977_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: Letters Archived (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:0 w:1)
+	fn restore_letter(n: u32, b: u32, ) -> Weight {
+		(52_468_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((1_082_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 0
+			.saturating_add((3_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
 }
 
 impl WeightInfo for () {
@@ -106,22 +786,147 @@ impl WeightInfo for () {
 	// Storage: Letters AllLettersCount (r:1 w:1)
 	// Storage: Letters Letters (r:0 w:1)
 	// Storage: Letters AllLettersArray (r:0 w:1)
-	// Storage: Letters OwnedLettersArray (r:0 w:1)
-	// Storage: Letters OwnedLettersIndex (r:0 w:1)
+	// Storage: Letters OwnedLetters (r:0 w:1)
 	// Storage: Letters AllLettersIndex (r:0 w:1)
+	// Storage: Letters DepositHolder (r:0 w:1)
+	// Storage: Letters RentPaidUntil (r:0 w:1)
 	fn init_letter(_t: u32, _a: u32, ) -> Weight {
 		(68_547_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
 	}
 	// Storage: Letters Letters (r:1 w:1)
 	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters PageWriter (r:0 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:0 w:1)
 	fn write_page(p: u32, ) -> Weight {
-		(60_317_000 as Weight)
+		(64_871_000 as Weight)
 			// Standard Error: 5_000
 			.saturating_add((1_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Frozen (r:1 w:0)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters PageWriter (r:0 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:0 w:1)
+	fn write_pages(n: u32, b: u32, ) -> Weight {
+		(71_305_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((3_514_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 0
+			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Frozen (r:1 w:0)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:0)
+	// Storage: Letters PageWriter (r:1 w:1)
+	// Storage: Letters RedactedPages (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters OffchainPages (r:1 w:0)
+	// Storage: Letters CompressedPages (r:1 w:0)
+	// Storage: Letters PageStore (r:2 w:2)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:1 w:2)
+	fn append_to_page(b: u32, ) -> Weight {
+		(70_118_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Frozen (r:1 w:0)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters PageWriter (r:0 w:1)
+	// Storage: Letters OffchainPages (r:0 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn write_offchain_page() -> Weight {
+		(61_937_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Frozen (r:1 w:0)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters PageWriter (r:0 w:1)
+	// Storage: Letters OffchainPages (r:0 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn write_indexed_page(p: u32, ) -> Weight {
+		(63_104_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((3_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Frozen (r:1 w:0)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters PageWriter (r:0 w:1)
+	// Storage: Letters CompressedPages (r:0 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:0 w:1)
+	fn write_compressed_page(c: u32, ) -> Weight {
+		(66_513_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((1_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn decompress_page(d: u32, ) -> Weight {
+		(3_412_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(d as Weight))
 	}
 	// Storage: Letters Letters (r:1 w:1)
 	// Storage: Letters LetterOwner (r:1 w:0)
@@ -131,23 +936,538 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:1)
 	// Storage: Letters OwnedLettersCount (r:2 w:2)
-	// Storage: Letters OwnedLettersIndex (r:1 w:2)
-	// Storage: Letters OwnedLettersArray (r:1 w:3)
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
 	fn transfer() -> Weight {
-		(87_866_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+		(71_204_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters ChapterOf (r:1 w:0)
+	// Storage: Letters RevenueSplits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Letters OwnedLettersCount (r:2 w:2)
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn buy_letter(s: u32, ) -> Weight {
+		(104_912_000 as Weight)
+			// Standard Error: 14_000
+			.saturating_add((27_518_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Storage: Letters Letters (r:1 w:0)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Prints (r:1 w:0)
+	// Storage: Letters Editions (r:1 w:1)
+	fn create_edition() -> Weight {
+		(31_204_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters Editions (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:2)
+	// Storage: Letters LetterOwner (r:2 w:1)
+	// Storage: Letters EditionSupply (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Letters OwnedLettersCount (r:1 w:1)
+	// Storage: Letters AllLettersCount (r:1 w:1)
+	// Storage: Letters Nonce (r:1 w:1)
+	// Storage: Letters Prints (r:0 w:1)
+	// Storage: Letters AllLettersArray (r:0 w:1)
+	// Storage: Letters OwnedLetters (r:0 w:1)
+	// Storage: Letters AllLettersIndex (r:0 w:1)
+	// Storage: Letters DepositHolder (r:0 w:1)
+	// Storage: Letters RentPaidUntil (r:0 w:1)
+	// Storage: Letters LivePrints (r:1 w:1)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	fn mint_print() -> Weight {
+		(106_912_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(17 as Weight))
+	}
+	// Storage: Letters CollectionsCount (r:1 w:1)
+	// Storage: Letters Collections (r:0 w:1)
+	// Storage: Letters DepositParameters (r:1 w:0)
+	fn create_collection(t: u32, _d: u32, ) -> Weight {
+		(41_825_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((2_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Letters Collections (r:1 w:0)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters LetterCollections (r:1 w:1)
+	// Storage: Letters PendingEntries (r:1 w:1)
+	// Storage: Letters CollectionEntries (r:1 w:1)
+	// Storage: Letters PendingEntryCount (r:1 w:1)
+	// Storage: Letters LetterPendingEntries (r:0 w:1)
+	// Storage: Letters LetterEntryCount (r:1 w:1)
+	fn add_to_collection() -> Weight {
+		(38_412_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Letters Collections (r:1 w:0)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters PendingEntries (r:1 w:1)
+	// Storage: Letters PendingEntryCount (r:1 w:1)
+	// Storage: Letters LetterPendingEntries (r:0 w:1)
+	// Storage: Letters LetterCollections (r:1 w:1)
+	// Storage: Letters CollectionEntries (r:1 w:1)
+	// Storage: Letters LetterEntryCount (r:1 w:1)
+	fn remove_from_collection() -> Weight {
+		(35_967_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Letters Collections (r:1 w:0)
+	// Storage: Letters CollectionEntries (r:1 w:1)
+	fn move_in_collection() -> Weight {
+		(27_130_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters Collections (r:1 w:1)
+	// Storage: Letters CollectionEntries (r:1 w:1)
+	// Storage: Letters PendingEntries (r:1 w:1)
+	// Storage: Letters PendingEntryCount (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters LetterCollections (r:0 w:1)
+	// Storage: Letters LetterPendingEntries (r:0 w:1)
+	// Storage: Letters LetterEntryCount (r:1 w:1)
+	fn destroy_collection(e: u32, p: u32, ) -> Weight {
+		(36_284_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((2_147_000 as Weight).saturating_mul(e as Weight))
+			// Standard Error: 16_000
+			.saturating_add((6_902_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: Letters BooksCount (r:1 w:1)
+	// Storage: Letters Books (r:0 w:1)
+	// Storage: Letters DepositParameters (r:1 w:0)
+	fn create_book(t: u32, ) -> Weight {
+		(39_561_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((2_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Letters Books (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters ChapterOf (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:0)
+	// Storage: Letters Frozen (r:1 w:0)
+	// Storage: Letters Hidden (r:1 w:0)
+	// Storage: Letters DepositParameters (r:1 w:0)
+	fn add_chapter(t: u32, ) -> Weight {
+		(42_307_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((2_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// Storage: Letters Books (r:1 w:1)
+	// Storage: Letters ChapterOf (r:1 w:1)
+	fn detach_chapter() -> Weight {
+		(37_884_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Letters Books (r:1 w:1)
+	fn set_book_price() -> Weight {
+		(24_915_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters Books (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:1)
 	// Storage: Letters Letters (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Letters OwnedLettersCount (r:2 w:2)
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn transfer_book(c: u32, ) -> Weight {
+		(45_102_000 as Weight)
+			// Standard Error: 31_000
+			.saturating_add((84_716_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((8 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((10 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: Letters Books (r:1 w:1)
 	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:1)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Letters OwnedLettersCount (r:2 w:2)
-	// Storage: Letters OwnedLettersIndex (r:1 w:2)
-	// Storage: Letters OwnedLettersArray (r:1 w:3)
-	fn buy_letter() -> Weight {
-		(114_926_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters Hidden (r:1 w:0)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn buy_book(c: u32, ) -> Weight {
+		(72_648_000 as Weight)
+			// Standard Error: 31_000
+			.saturating_add((84_716_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((9 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((10 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: Letters Books (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters ChapterOf (r:0 w:1)
+	fn destroy_book(c: u32, ) -> Weight {
+		(31_606_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((2_173_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Contributors (r:1 w:1)
+	fn invite_contributor() -> Weight {
+		(30_482_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters Contributors (r:1 w:1)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	fn revoke_contributor() -> Weight {
+		(29_736_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:1)
+	fn seal_letter() -> Weight {
+		(28_964_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:0)
+	// Storage: Letters RevenueSplits (r:0 w:1)
+	fn set_revenue_split(s: u32, ) -> Weight {
+		(27_203_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((412_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters ChapterOf (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:0)
+	// Storage: Letters Fractionalized (r:1 w:1)
+	// Storage: Letters OwnedLettersCount (r:2 w:2)
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters FractionNonce (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn fractionalize() -> Weight {
+		(118_452_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
 	}
+	// Storage: Letters Fractionalized (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters OwnedLettersCount (r:2 w:2)
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:0)
+	fn redeem() -> Weight {
+		(121_093_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(16 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters ChapterOf (r:1 w:0)
+	// Storage: Letters LivePrints (r:2 w:1)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters OwnedLettersCount (r:1 w:1)
+	// Storage: Letters OwnedLetters (r:0 w:1)
+	// Storage: Letters AllLettersCount (r:1 w:1)
+	// Storage: Letters AllLettersIndex (r:1 w:2)
+	// Storage: Letters AllLettersArray (r:1 w:2)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters LetterCollections (r:1 w:1)
+	// Storage: Letters LetterPendingEntries (r:1 w:1)
+	// Storage: Letters PendingEntries (r:1 w:1)
+	// Storage: Letters PendingEntryCount (r:1 w:1)
+	// Storage: Letters PageWriter (r:1 w:1)
+	// Storage: Letters RedactedPages (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters RevenueSplits (r:0 w:1)
+	// Storage: Letters Editions (r:0 w:1)
+	// Storage: Letters Prints (r:1 w:1)
+	// Storage: Letters EditionSupply (r:0 w:1)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:0 w:1)
+	// Storage: Letters Archived (r:0 w:1)
+	// Storage: Letters OffchainPages (r:1 w:1)
+	// Storage: Letters CompressedPages (r:1 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LetterEntryCount (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:1 w:1)
+	fn burn_letter(p: u32, e: u32, ) -> Weight {
+		(74_310_000 as Weight)
+			// Standard Error: 9_000
+			.saturating_add((12_406_000 as Weight).saturating_mul(p as Weight))
+			// Standard Error: 21_000
+			.saturating_add((9_118_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(21 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(e as Weight)))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Frozen (r:1 w:1)
+	fn freeze() -> Weight {
+		(21_873_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Frozen (r:1 w:1)
+	fn thaw() -> Weight {
+		(22_410_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters RedactedPages (r:1 w:1)
+	// Storage: Letters PageWriter (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters OffchainPages (r:1 w:1)
+	// Storage: Letters CompressedPages (r:0 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters PageDeposits (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	fn force_redact_page(r: u32, ) -> Weight {
+		(9_214_000 as Weight)
+			// Standard Error: 12_000
+			.saturating_add((36_481_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((11 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((8 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters ChapterOf (r:1 w:1)
+	// Storage: Letters Books (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Fractionalized (r:1 w:1)
+	// Storage: Letters OwnedLettersCount (r:1 w:1)
+	// Storage: Letters OwnedLetters (r:0 w:1)
+	// Storage: Letters AllLettersCount (r:1 w:1)
+	// Storage: Letters AllLettersIndex (r:1 w:2)
+	// Storage: Letters AllLettersArray (r:1 w:2)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	// Storage: Letters LetterCollections (r:1 w:1)
+	// Storage: Letters LetterPendingEntries (r:1 w:1)
+	// Storage: Letters PendingEntries (r:1 w:1)
+	// Storage: Letters PendingEntryCount (r:1 w:1)
+	// Storage: Letters PageWriter (r:1 w:1)
+	// Storage: Letters RedactedPages (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters RevenueSplits (r:0 w:1)
+	// Storage: Letters Editions (r:0 w:1)
+	// Storage: Letters EditionSupply (r:0 w:1)
+	// Storage: Letters Prints (r:1 w:1)
+	// Storage: Letters Frozen (r:0 w:1)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:0 w:1)
+	// Storage: Letters Archived (r:0 w:1)
+	// Storage: Letters OffchainPages (r:1 w:1)
+	// Storage: Letters CompressedPages (r:1 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LivePrints (r:1 w:1)
+	// Storage: Letters LetterEntryCount (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:1 w:1)
+	fn force_burn_letter(p: u32, e: u32, ) -> Weight {
+		(96_524_000 as Weight)
+			// Standard Error: 9_000
+			.saturating_add((12_517_000 as Weight).saturating_mul(p as Weight))
+			// Standard Error: 21_000
+			.saturating_add((9_204_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(27 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(e as Weight)))
+	}
+	// Storage: Letters Letters (r:1 w:0)
+	// Storage: Letters Reports (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters Hidden (r:1 w:1)
+	fn report_letter() -> Weight {
+		(38_615_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Letters Reports (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters Hidden (r:1 w:1)
+	fn resolve_report() -> Weight {
+		(36_087_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Fractionalized (r:2 w:0)
+	// Storage: Letters ChapterOf (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Letters OwnedLettersCount (r:2 w:2)
+	// Storage: Letters OwnedLetters (r:0 w:2)
+	// Storage: Letters DepositHolder (r:1 w:1)
+	// Storage: Letters Contributors (r:0 w:1)
+	// Storage: Letters ContributorDeposits (r:1 w:1)
+	fn force_transfer() -> Weight {
+		(58_937_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:1)
+	fn force_set_price() -> Weight {
+		(23_516_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters Fractionalized (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	fn force_set_deposit() -> Weight {
+		(32_779_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Letters AllLettersCount (r:1 w:0)
+	// Storage: Letters OwnedLettersCount (r:1 w:1)
+	// Storage: Letters AllLettersArray (r:1 w:0)
+	// Storage: Letters LetterOwner (r:1 w:0)
+	// Storage: Letters OwnedLetters (r:0 w:1)
+	fn force_rebuild_owner_index(n: u32, ) -> Weight {
+		(14_210_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((9_847_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Letters DepositParameters (r:0 w:1)
+	fn set_deposit_parameters() -> Weight {
+		(14_102_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters DepositParameters (r:1 w:0)
+	// Storage: Letters RedactedPages (r:1 w:0)
+	// Storage: Letters PageWriter (r:1 w:0)
+	// Storage: Letters ContributorDeposits (r:2 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Letters Archived (r:1 w:0)
+	// Storage: Letters OffchainPages (r:1 w:0)
+	// Storage: Letters PageStore (r:1 w:0)
+	// Storage: Letters PageDeposits (r:1 w:1)
+	fn recompute_deposit(p: u32, ) -> Weight {
+		(48_233_000 as Weight)
+			// Standard Error: 6_000
+			.saturating_add((9_871_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((7 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: Letters RentPaidUntil (r:1 w:1)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters Archived (r:0 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:1 w:1)
+	fn collect_rent(p: u32, ) -> Weight {
+		(38_316_000 as Weight)
+			// Standard Error: 41_000
+			.saturating_add((18_977_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: Letters Archived (r:1 w:1)
+	// Storage: Letters DepositHolder (r:1 w:0)
+	// Storage: Letters Letters (r:1 w:1)
+	// Storage: Letters RentPaidUntil (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Letters PageStore (r:1 w:1)
+	// Storage: Letters LetterBytes (r:1 w:1)
+	// Storage: Letters PageDeposits (r:0 w:1)
+	fn restore_letter(n: u32, b: u32, ) -> Weight {
+		(52_468_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((1_082_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 0
+			.saturating_add((3_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
 }